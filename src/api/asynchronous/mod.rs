@@ -0,0 +1,184 @@
+//! Contains the [`AsyncApi`](AsyncApi) struct.
+#![deny(warnings, missing_docs)]
+
+use futures::Future;
+use reqwest::async::Client;
+
+use api::DEFAULT_BASE_URL;
+use api::errors::DeezerError;
+use api::objects::*;
+
+/// Non blocking client for Deezer's public API.
+///
+/// Exposes the same fetchers as [`Api`](Api), as futures resolving to the same
+/// structs and [`DeezerError`](DeezerError). Requires the `async` feature.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate deezer_metadata;
+/// # extern crate futures;
+/// # extern crate tokio;
+/// # use deezer_metadata::api::AsyncApi;
+/// # use futures::future::join_all;
+/// # use futures::Future;
+/// # fn main() {
+/// // Get a new Api Client
+/// let deezer = AsyncApi::new();
+///
+/// // Fetch as many albums as you want concurrently
+/// let albums = join_all(vec![
+///     deezer.get_album(302127),
+///     deezer.get_album(302128),
+///     deezer.get_album(302129),
+/// ]);
+///
+/// let albums = tokio::runtime::Runtime::new().unwrap()
+///     .block_on(albums)
+///     .unwrap();
+/// # assert_eq!(albums[0].id, 302127);
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct AsyncApi {
+    base_url: String,
+    client: Client,
+}
+
+impl AsyncApi {
+
+    /// Returns an `AsyncApi` with a default `reqwest::async::Client`.
+    pub fn new() -> Self {
+        Self::with_client(Client::new())
+    }
+
+    /// Returns an `AsyncApi` using the given client (e.g. one configured with a proxy).
+    pub fn with_client(client: Client) -> Self {
+
+        AsyncApi {
+            base_url: DEFAULT_BASE_URL.to_owned(),
+            client,
+        }
+    }
+
+    /// Sets the url every api path is appended to (e.g. a local mock server).
+    pub fn with_base_url(mut self, base_url: &str) -> Self {
+        self.base_url = base_url.trim_end_matches('/').to_owned();
+        self
+    }
+
+    /// Returns the url every api path is appended to.
+    pub fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    /// Fetches the body behind the given api path.
+    fn fetch(&self, path: &str) -> impl Future<Item = String, Error = DeezerError> {
+        self.client.get(&format!("{}/{}", self.base_url, path))
+            .send()
+            .map_err(DeezerError::from)
+            .and_then(|resp| {
+
+                if !resp.status().is_success() {
+                    return Err(DeezerError::HttpStatus(resp.status().as_u16()));
+                }
+
+                Ok(resp)
+            })
+            .and_then(|mut resp| resp.text().map_err(DeezerError::from))
+    }
+}
+
+impl Default for AsyncApi {
+
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl AsyncApi {
+
+    /// Returns the [`Track`](Track) with the given id.
+    pub fn get_track(&self, id: u32) -> impl Future<Item = track::Track, Error = DeezerError> {
+        self.fetch(&track::get_track_path(id))
+            .and_then(|json| track::Track::new(&json))
+    }
+
+    /// Returns the [`Artist`](Artist) with the given id.
+    pub fn get_artist(&self, id: u32) -> impl Future<Item = artist::Artist, Error = DeezerError> {
+        self.fetch(&artist::get_artist_path(id))
+            .and_then(|json| artist::Artist::new(&json))
+    }
+
+    /// Returns the [`Album`](Album) with the given id.
+    pub fn get_album(&self, id: u32) -> impl Future<Item = album::Album, Error = DeezerError> {
+        self.fetch(&album::get_album_path(id))
+            .and_then(|json| album::Album::new(&json))
+    }
+
+    /// Returns the [`Genre`](Genre) with the given id.
+    pub fn get_genre(&self, id: u32) -> impl Future<Item = genre::Genre, Error = DeezerError> {
+        self.fetch(&genre::get_genre_path(id))
+            .and_then(|json| genre::Genre::new(&json))
+    }
+
+    /// Returns the [`Podcast`](Podcast) with the given id.
+    pub fn get_podcast(&self, id: u32) -> impl Future<Item = podcast::Podcast, Error = DeezerError> {
+        self.fetch(&podcast::get_podcast_path(id))
+            .and_then(|json| podcast::Podcast::new(&json))
+    }
+
+    /// Returns the [`Episode`](Episode) with the given id.
+    pub fn get_episode(&self, id: u32) -> impl Future<Item = episode::Episode, Error = DeezerError> {
+        self.fetch(&episode::get_episode_path(id))
+            .and_then(|json| episode::Episode::new(&json))
+    }
+
+    /// Returns the [`Comment`](Comment) with the given id.
+    pub fn get_comment(&self, id: u32) -> impl Future<Item = comment::Comment, Error = DeezerError> {
+        self.fetch(&comment::get_comment_path(id))
+            .and_then(|json| comment::Comment::new(&json))
+    }
+
+    /// Returns the [`User`](User) with the given id.
+    pub fn get_user(&self, id: u32) -> impl Future<Item = user::User, Error = DeezerError> {
+        self.fetch(&user::get_user_path(id))
+            .and_then(|json| user::User::new(&json))
+    }
+
+    /// Returns the [`Playlist`](Playlist) with the given id.
+    pub fn get_playlist(&self, id: u64) -> impl Future<Item = playlist::Playlist, Error = DeezerError> {
+        self.fetch(&playlist::get_playlist_path(id))
+            .and_then(|json| playlist::Playlist::new(&json))
+    }
+
+    /// Returns the [`Editorial`](Editorial) with the given id.
+    pub fn get_editorial(&self, id: u32) -> impl Future<Item = editorial::Editorial, Error = DeezerError> {
+        self.fetch(&editorial::get_editorial_path(id))
+            .and_then(|json| editorial::Editorial::new(&json))
+    }
+
+    /// Returns the [`Radio`](Radio) with the given id.
+    pub fn get_radio(&self, id: u32) -> impl Future<Item = radio::Radio, Error = DeezerError> {
+        self.fetch(&radio::get_radio_path(id))
+            .and_then(|json| radio::Radio::new(&json))
+    }
+
+    /// Returns the [`Info`](Info) for the current country.
+    pub fn get_info(&self) -> impl Future<Item = info::Info, Error = DeezerError> {
+        self.fetch(&info::get_info_path())
+            .and_then(|json| info::Info::new(&json))
+    }
+
+    /// Returns the [`Chart`](Chart) across every genre.
+    pub fn get_chart(&self) -> impl Future<Item = chart::Chart, Error = DeezerError> {
+        self.fetch(&chart::get_chart_path())
+            .and_then(|json| chart::Chart::new(&json))
+    }
+
+    /// Returns the [`Options`](Options) for the current user.
+    pub fn get_options(&self) -> impl Future<Item = options::Options, Error = DeezerError> {
+        self.fetch(&options::get_options_path())
+            .and_then(|json| options::Options::new(&json))
+    }
+}
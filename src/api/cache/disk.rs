@@ -0,0 +1,572 @@
+//! Contains the [`DiskCache`](DiskCache) struct, keeping responses across runs.
+#![deny(warnings, missing_docs)]
+
+use std::fs::{
+    self,
+    File,
+};
+use std::io::{
+    self,
+    BufReader,
+    BufWriter,
+    Read,
+};
+use std::path::{
+    Path,
+    PathBuf,
+};
+use std::process;
+use std::sync::{
+    Mutex,
+    MutexGuard,
+};
+use std::sync::atomic::{
+    AtomicUsize,
+    Ordering,
+};
+use std::time::{
+    SystemTime,
+    UNIX_EPOCH,
+};
+
+use flate2::Compression;
+use flate2::read::GzDecoder;
+use flate2::write::GzEncoder;
+use url::Url;
+
+use api::cache::{
+    Cache,
+    CacheStats,
+    CacheTtls,
+};
+use api::errors::DeezerError;
+
+/// The extension of the files responses are kept in.
+const EXTENSION: &str = "json.gz";
+
+/// The extension of the files responses are written to before being renamed.
+const TMP_EXTENSION: &str = "tmp";
+
+/// Tells apart the temporary files of concurrent writes in the same process.
+static WRITES: AtomicUsize = AtomicUsize::new(0);
+
+/// A [`Cache`](Cache) keeping every response in its own gzipped file in a directory,
+/// so they can be reused by later runs.
+///
+/// Files are laid out by api path (`playlist/908622995/tracks/...`), so a write
+/// only drops the directory of what it changed, and named after the FNV-1a hash
+/// of the url, so the same url always lands in the same file. Access tokens are
+/// never written: responses to authenticated requests are told apart by a hash
+/// of their token. When the directory grows over the size limit, the files
+/// written the longest ago are dropped first.
+///
+/// In [`offline`](#method.offline) mode, responses are served even once expired
+/// and anything not cached fails with [`DeezerError::Offline`](DeezerError::Offline),
+/// so a run can be reproduced without reaching Deezer.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate deezer_metadata;
+/// # use deezer_metadata::api::Api;
+/// # use deezer_metadata::api::cache::disk::DiskCache;
+/// # use deezer_metadata::api::errors::DeezerError;
+/// # use deezer_metadata::api::transport::MemoryTransport;
+/// # fn main() {
+/// # let dir = std::env::temp_dir().join(format!("deezer_metadata_doc_{}", std::process::id()));
+/// # let transport = MemoryTransport::new()
+/// #     .with_body("https://api.deezer.com/genre/132", r#"{
+/// #         "id": 132, "name": "Pop", "picture": "", "picture_small": "",
+/// #         "picture_medium": "", "picture_big": "", "picture_xl": ""
+/// #     }"#);
+/// // The nightly run fills the cache
+/// let deezer = Api::builder()
+/// #   .transport(transport)
+///     .cache(DiskCache::open(&dir).unwrap().with_max_bytes(50 * 1024 * 1024))
+///     .build();
+///
+/// let genre = deezer.get_genre(132).unwrap();
+///
+/// // Later runs only read it back
+/// let offline = Api::builder()
+///     .cache(DiskCache::open(&dir).unwrap().offline(true))
+///     .build();
+///
+/// assert_eq!(offline.get_genre(132).unwrap().name, genre.name);
+///
+/// match offline.get_genre(116) {
+///     Err(DeezerError::Offline(url)) => assert_eq!(url, "https://api.deezer.com/genre/116"),
+///     other => panic!("expected a cache miss, got {:?}", other),
+/// }
+/// # std::fs::remove_dir_all(&dir).unwrap();
+/// # }
+/// ```
+///
+/// Access tokens never reach the disk, yet users don't share responses:
+///
+/// ```rust
+/// # extern crate deezer_metadata;
+/// # extern crate flate2;
+/// # use std::fs;
+/// # use std::io::Read;
+/// # use std::path::{Path, PathBuf};
+/// # use deezer_metadata::api::cache::Cache;
+/// # use deezer_metadata::api::cache::disk::DiskCache;
+/// # use flate2::read::GzDecoder;
+/// # fn files(dir: &Path, found: &mut Vec<PathBuf>) {
+/// #     for entry in fs::read_dir(dir).unwrap() {
+/// #         let path = entry.unwrap().path();
+/// #         if path.is_dir() { files(&path, found) } else { found.push(path) }
+/// #     }
+/// # }
+/// # fn main() {
+/// # let dir = std::env::temp_dir().join(format!("deezer_metadata_doc_token_{}", std::process::id()));
+/// let cache = DiskCache::open(&dir).unwrap();
+///
+/// cache.insert("https://api.deezer.com/user/me?access_token=t0k3n", "user", r#"{"id": 5}"#);
+///
+/// assert!(cache.get("https://api.deezer.com/user/me?access_token=t0k3n").is_some());
+/// assert!(cache.get("https://api.deezer.com/user/me?access_token=0th3r").is_none());
+/// assert!(cache.get("https://api.deezer.com/user/me").is_none());
+///
+/// # let mut found = Vec::new();
+/// # files(&dir, &mut found);
+/// # assert_eq!(found.len(), 1);
+/// # for file in found {
+/// #     let mut json = String::new();
+/// #     GzDecoder::new(fs::File::open(&file).unwrap()).read_to_string(&mut json).unwrap();
+/// #     assert!(!json.contains("t0k3n") && !file.to_string_lossy().contains("t0k3n"));
+/// # }
+/// // Writes drop the responses of every user
+/// cache.invalidate("https://api.deezer.com/user/me");
+/// assert!(cache.get("https://api.deezer.com/user/me?access_token=t0k3n").is_none());
+/// # fs::remove_dir_all(&dir).unwrap();
+/// # }
+/// ```
+///
+/// Temporary files left behind by interrupted writes are removed on opening,
+/// and urls that don't parse are neither cached nor able to invalidate anything:
+///
+/// ```rust
+/// # extern crate deezer_metadata;
+/// # use std::fs;
+/// # use deezer_metadata::api::cache::Cache;
+/// # use deezer_metadata::api::cache::disk::DiskCache;
+/// # fn main() {
+/// # let dir = std::env::temp_dir().join(format!("deezer_metadata_doc_leftovers_{}", std::process::id()));
+/// # fs::create_dir_all(&dir).unwrap();
+/// let leftover = dir.join("0123456789abcdef.json.4242.0.tmp");
+/// fs::write(&leftover, b"half written").unwrap();
+///
+/// let cache = DiskCache::open(&dir).unwrap();
+/// assert!(!leftover.exists());
+///
+/// cache.insert("https://api.deezer.com/genre/132", "genre", r#"{"id": 132}"#);
+/// cache.invalidate("not a url");
+/// assert!(cache.get("https://api.deezer.com/genre/132").is_some());
+/// # fs::remove_dir_all(&dir).unwrap();
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct DiskCache {
+    dir: PathBuf,
+    ttls: CacheTtls,
+    max_bytes: Option<u64>,
+    offline: bool,
+    stats: Mutex<CacheStats>,
+
+    /// The total size of the files, once a size limit made it worth tracking
+    size: Mutex<Option<u64>>,
+}
+
+/// What is written, gzipped, in each file.
+#[derive(Deserialize, Serialize)]
+struct DiskEntry {
+
+    /// The url without its access token
+    url: String,
+
+    /// The hash of the access token, if any
+    #[serde(default)]
+    token: Option<u64>,
+
+    expires_at: u64,
+    body: String,
+}
+
+/// A cache key split into the url written to disk and the hash of its access token.
+struct Key {
+    url: String,
+    token: Option<u64>,
+}
+
+impl Key {
+
+    /// Moves the access token out of the query string of the url, if it has one.
+    fn new(url: &str) -> Self {
+
+        let (path, query) = match url.find('?') {
+            Some(at) => (&url[..at], &url[at + 1..]),
+            None => return Key { url: url.to_owned(), token: None },
+        };
+
+        let mut token = None;
+        let pairs: Vec<&str> = query.split('&')
+            .filter(|pair| match pair.strip_prefix("access_token=") {
+                Some(value) => {
+                    token = Some(fnv1a(value.as_bytes()));
+                    false
+                },
+                None => true,
+            })
+            .collect();
+
+        let url = if pairs.is_empty() {
+            path.to_owned()
+        } else {
+            format!("{}?{}", path, pairs.join("&"))
+        };
+
+        Key { url, token }
+    }
+}
+
+impl DiskCache {
+
+    /// Returns a `DiskCache` keeping its files in the given directory, creating it if needed,
+    /// with the default [`CacheTtls`](CacheTtls) and no size limit.
+    pub fn open<P: AsRef<Path>>(dir: P) -> Result<Self, DeezerError> {
+
+        fs::create_dir_all(dir.as_ref()).map_err(DeezerError::Cache)?;
+
+        // Writes interrupted by a crash leave their temporary file behind, a write of
+        // another run still going on merely fails to cache its response
+        let mut leftovers = Vec::new();
+        walk(dir.as_ref(), TMP_EXTENSION, &mut leftovers).map_err(DeezerError::Cache)?;
+
+        for (path, _) in leftovers {
+            let _ = fs::remove_file(path);
+        }
+
+        Ok(DiskCache {
+            dir: dir.as_ref().to_owned(),
+            ttls: CacheTtls::default(),
+            max_bytes: None,
+            offline: false,
+            stats: Mutex::new(CacheStats::default()),
+            size: Mutex::new(None),
+        })
+    }
+
+    /// Keeps responses for the given times.
+    pub fn with_ttls(mut self, ttls: CacheTtls) -> Self {
+        self.ttls = ttls;
+        self
+    }
+
+    /// Keeps the files (compressed) under the given total size.
+    pub fn with_max_bytes(mut self, max_bytes: u64) -> Self {
+        self.max_bytes = Some(max_bytes);
+        self
+    }
+
+    /// Serves only from the cache when `true`, expired responses included,
+    /// failing every request it can't answer.
+    pub fn offline(mut self, offline: bool) -> Self {
+        self.offline = offline;
+        self
+    }
+
+    /// Returns the directory the files are kept in.
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// Deletes every cached response.
+    pub fn clear(&self) -> Result<(), DeezerError> {
+
+        for (path, _) in self.files().map_err(DeezerError::Cache)? {
+            fs::remove_file(path).map_err(DeezerError::Cache)?;
+        }
+
+        *self.size() = None;
+
+        Ok(())
+    }
+
+    /// Returns the directory the responses to the url, and to every url below it, are kept in:
+    /// one directory for the host, then one per segment of the path.
+    ///
+    /// Returns `None` if the url doesn't parse, rather than a directory holding other responses.
+    fn dir_of(&self, url: &str) -> Option<PathBuf> {
+
+        let url = Url::parse(url).ok()?;
+
+        let origin = url.origin().ascii_serialization();
+        let mut dir = self.dir.join(format!("{:016x}", fnv1a(origin.as_bytes())));
+
+        for segment in url.path_segments().into_iter().flatten().filter(|s| !s.is_empty()) {
+
+            let is_safe = segment.len() <= 64
+                && segment.bytes().all(|b| b.is_ascii_alphanumeric() || b == b'-' || b == b'_');
+
+            // Ids and names are kept as is, anything else (e.g. `isrc:GBDUW0000059`) is hashed
+            if is_safe {
+                dir.push(segment);
+            } else {
+                dir.push(format!("~{:016x}", fnv1a(segment.as_bytes())));
+            }
+        }
+
+        Some(dir)
+    }
+
+    /// Returns the file the response to the url is kept in, if the url parses.
+    fn path(&self, key: &Key) -> Option<PathBuf> {
+
+        let name = match key.token {
+            Some(token) => format!("{:016x}-{:016x}.{}", fnv1a(key.url.as_bytes()), token, EXTENSION),
+            None => format!("{:016x}.{}", fnv1a(key.url.as_bytes()), EXTENSION),
+        };
+
+        self.dir_of(&key.url).map(|dir| dir.join(name))
+    }
+
+    /// Returns every cached file along with its metadata.
+    fn files(&self) -> io::Result<Vec<(PathBuf, fs::Metadata)>> {
+        let mut files = Vec::new();
+
+        walk(&self.dir, EXTENSION, &mut files)?;
+        Ok(files)
+    }
+
+    /// A panic while the lock was held can't leave the size half updated, so it's safe to reuse.
+    fn size(&self) -> MutexGuard<'_, Option<u64>> {
+        self.size.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    /// Records that files of the given total size were removed from the directory.
+    fn untrack(&self, removed: u64) {
+        if let Some(ref mut size) = *self.size() {
+            *size = size.saturating_sub(removed);
+        }
+    }
+
+    fn read(path: &Path) -> io::Result<DiskEntry> {
+
+        let mut json = String::new();
+        GzDecoder::new(BufReader::new(File::open(path)?)).read_to_string(&mut json)?;
+
+        serde_json::from_str(&json).map_err(io::Error::from)
+    }
+
+    /// Writes to a temporary file first, so other runs never read half written files.
+    fn write(&self, path: &Path, entry: &DiskEntry) -> io::Result<()> {
+
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+
+        // Threads of the same process may write the same url at once
+        let write = WRITES.fetch_add(1, Ordering::Relaxed);
+        let tmp = path.with_extension(format!("{}.{}.{}", process::id(), write, TMP_EXTENSION));
+
+        let result = File::create(&tmp).and_then(|file| {
+            let mut encoder = GzEncoder::new(BufWriter::new(file), Compression::default());
+            serde_json::to_writer(&mut encoder, entry)?;
+
+            encoder.finish()?.into_inner().map_err(|e| e.into_error())?;
+            fs::rename(&tmp, path)
+        });
+
+        if result.is_err() {
+            let _ = fs::remove_file(&tmp);
+        }
+
+        result
+    }
+
+    /// Records the size of a newly written file and, if the directory grew over the size
+    /// limit, drops the files written the longest ago until it fits again.
+    fn shrink(&self, added: u64, replaced: u64) -> io::Result<u64> {
+
+        let max_bytes = match self.max_bytes {
+            Some(max_bytes) => max_bytes,
+            None => return Ok(0),
+        };
+
+        let mut size = self.size();
+
+        // Only the first write after opening the cache goes through the whole directory
+        let total = match *size {
+            Some(total) => (total + added).saturating_sub(replaced),
+            None => self.files()?.iter().map(|(_, metadata)| metadata.len()).sum(),
+        };
+
+        *size = Some(total);
+
+        if total <= max_bytes {
+            return Ok(0);
+        }
+
+        let mut files = self.files()?;
+        let mut total: u64 = files.iter().map(|(_, metadata)| metadata.len()).sum();
+
+        files.sort_by_key(|(_, metadata)| metadata.modified().unwrap_or(UNIX_EPOCH));
+
+        let mut evicted = 0;
+
+        for (path, metadata) in files {
+            if total <= max_bytes {
+                break;
+            }
+
+            fs::remove_file(path)?;
+            total -= metadata.len();
+            evicted += 1;
+        }
+
+        *size = Some(total);
+
+        Ok(evicted)
+    }
+
+    /// Returns the body kept for the key, dropping its file if it expired.
+    fn lookup(&self, key: &Key) -> Option<String> {
+
+        let path = self.path(key)?;
+        let entry = DiskCache::read(&path).ok()?;
+
+        // Another url hashing to the same file doesn't count
+        if entry.url != key.url || entry.token != key.token {
+            return None;
+        }
+
+        if self.offline || entry.expires_at > now() {
+            return Some(entry.body);
+        }
+
+        let size = fs::metadata(&path).map(|metadata| metadata.len()).unwrap_or(0);
+
+        if fs::remove_file(&path).is_ok() {
+            self.untrack(size);
+        }
+
+        None
+    }
+
+    fn update_stats<F: FnOnce(&mut CacheStats)>(&self, update: F) {
+        update(&mut self.stats.lock().unwrap_or_else(|poisoned| poisoned.into_inner()));
+    }
+}
+
+impl Cache for DiskCache {
+
+    fn get(&self, url: &str) -> Option<String> {
+
+        let body = self.lookup(&Key::new(url));
+
+        self.update_stats(|stats| match body {
+            Some(_) => stats.hits += 1,
+            None => stats.misses += 1,
+        });
+
+        body
+    }
+
+    fn insert(&self, url: &str, object_type: &str, body: &str) {
+
+        // Reproducible runs must not change what they read
+        if self.offline {
+            return;
+        }
+
+        let key = Key::new(url);
+        let path = match self.path(&key) {
+            Some(path) => path,
+            None => return,
+        };
+
+        let entry = DiskEntry {
+            url: key.url,
+            token: key.token,
+            expires_at: now() + self.ttls.ttl(object_type).as_secs(),
+            body: body.to_owned(),
+        };
+
+        let replaced = fs::metadata(&path).map(|metadata| metadata.len()).unwrap_or(0);
+
+        // Failing to cache a response shouldn't fail the request
+        if self.write(&path, &entry).is_ok() {
+            let added = fs::metadata(&path).map(|metadata| metadata.len()).unwrap_or(0);
+
+            let evicted = self.shrink(added, replaced).unwrap_or(0);
+            self.update_stats(|stats| stats.evictions += evicted);
+        }
+    }
+
+    fn invalidate(&self, url: &str) {
+
+        // The response to the url sits in its directory, along with the ones of every url below it
+        let mut files = Vec::new();
+
+        if let Some(dir) = self.dir_of(&Key::new(url).url) {
+            let _ = walk(&dir, EXTENSION, &mut files);
+        }
+
+        for (path, metadata) in files {
+            if fs::remove_file(path).is_ok() {
+                self.untrack(metadata.len());
+            }
+        }
+    }
+
+    fn stats(&self) -> CacheStats {
+
+        let mut stats = *self.stats.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        stats.entries = self.files().map(|files| files.len() as u64).unwrap_or(0);
+
+        stats
+    }
+
+    fn is_offline(&self) -> bool {
+        self.offline
+    }
+}
+
+/// Adds every file with the given extension below the directory, along with its metadata, to `files`.
+fn walk(dir: &Path, extension: &str, files: &mut Vec<(PathBuf, fs::Metadata)>) -> io::Result<()> {
+
+    for entry in fs::read_dir(dir)? {
+        let entry = entry?;
+        let path = entry.path();
+        let metadata = entry.metadata()?;
+
+        let is_match = path.file_name()
+            .and_then(|name| name.to_str())
+            .is_some_and(|name| name.ends_with(&format!(".{}", extension)));
+
+        if metadata.is_dir() {
+            walk(&path, extension, files)?;
+        } else if is_match {
+            files.push((path, metadata));
+        }
+    }
+
+    Ok(())
+}
+
+/// Seconds since the Unix epoch.
+fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_secs())
+        .unwrap_or(0)
+}
+
+/// 64 bits FNV-1a hash, stable across runs and platforms unlike `DefaultHasher`.
+fn fnv1a(bytes: &[u8]) -> u64 {
+    bytes.iter().fold(0xcbf2_9ce4_8422_2325, |hash, &byte| {
+        (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3)
+    })
+}
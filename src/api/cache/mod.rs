@@ -0,0 +1,529 @@
+//! Contains the [`Cache`](Cache) trait [`Api`](Api) can keep responses in.
+//! Also contains the size-bounded [`MemoryCache`](MemoryCache), the
+//! [`CacheTtls`](CacheTtls) deciding how long responses stay fresh and
+//! [`CacheStats`](CacheStats).
+//! The on-disk [`DiskCache`](disk::DiskCache) lives in [`disk`](disk).
+#![deny(warnings, missing_docs)]
+
+pub mod disk;
+
+use std::collections::{
+    BTreeMap,
+    HashMap,
+};
+use std::sync::{
+    Arc,
+    Mutex,
+    MutexGuard,
+};
+use std::time::{
+    Duration,
+    Instant,
+};
+
+/// Keeps the bodies of `GET` responses, keyed by the url they were fetched from.
+///
+/// The url includes the access token, if any, so users never share responses.
+/// Write requests [`invalidate`](#tymethod.invalidate) what they may have changed.
+pub trait Cache {
+
+    /// Returns the body cached for the url, unless there is none or it expired.
+    fn get(&self, url: &str) -> Option<String>;
+
+    /// Caches the body fetched from the url, which points at an object of the
+    /// given type (the first segment of the api path, e.g. `album` or `chart`).
+    fn insert(&self, url: &str, object_type: &str, body: &str);
+
+    /// Drops the body cached for the url, along with the ones of every url below it
+    /// (`playlist/908622995` also drops `playlist/908622995/tracks?index=25`).
+    fn invalidate(&self, url: &str);
+
+    /// Returns how the cache performed so far.
+    fn stats(&self) -> CacheStats;
+
+    /// Whether requests the cache can't answer must fail instead of reaching Deezer.
+    fn is_offline(&self) -> bool {
+        false
+    }
+}
+
+impl<T: Cache + ?Sized> Cache for Box<T> {
+
+    fn get(&self, url: &str) -> Option<String> {
+        (**self).get(url)
+    }
+
+    fn insert(&self, url: &str, object_type: &str, body: &str) {
+        (**self).insert(url, object_type, body)
+    }
+
+    fn invalidate(&self, url: &str) {
+        (**self).invalidate(url)
+    }
+
+    fn stats(&self) -> CacheStats {
+        (**self).stats()
+    }
+
+    fn is_offline(&self) -> bool {
+        (**self).is_offline()
+    }
+}
+
+impl<T: Cache + ?Sized> Cache for Arc<T> {
+
+    fn get(&self, url: &str) -> Option<String> {
+        (**self).get(url)
+    }
+
+    fn insert(&self, url: &str, object_type: &str, body: &str) {
+        (**self).insert(url, object_type, body)
+    }
+
+    fn invalidate(&self, url: &str) {
+        (**self).invalidate(url)
+    }
+
+    fn stats(&self) -> CacheStats {
+        (**self).stats()
+    }
+
+    fn is_offline(&self) -> bool {
+        (**self).is_offline()
+    }
+}
+
+/// How a [`Cache`](Cache) performed so far.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CacheStats {
+
+    /// The number of lookups answered from the cache
+    pub hits: u64,
+
+    /// The number of lookups that had to go to Deezer, expired entries included
+    pub misses: u64,
+
+    /// The number of entries dropped to make room for new ones
+    pub evictions: u64,
+
+    /// The number of entries currently cached
+    pub entries: u64,
+}
+
+/// How long responses stay fresh, by type of object.
+///
+/// Charts and searches change often and only stay a few minutes, while albums
+/// and tracks hardly ever change and stay a day.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CacheTtls {
+    default: Duration,
+    by_type: HashMap<String, Duration>,
+}
+
+impl CacheTtls {
+
+    /// Returns the `CacheTtls` keeping every type of object for the given time.
+    pub fn uniform(ttl: Duration) -> Self {
+
+        CacheTtls {
+            default: ttl,
+            by_type: HashMap::new(),
+        }
+    }
+
+    /// Keeps objects of the given type (e.g. `album`) for the given time.
+    pub fn with(mut self, object_type: &str, ttl: Duration) -> Self {
+        self.by_type.insert(object_type.to_owned(), ttl);
+        self
+    }
+
+    /// Keeps objects of any type not set with [`with`](#method.with) for the given time.
+    pub fn with_default(mut self, ttl: Duration) -> Self {
+        self.default = ttl;
+        self
+    }
+
+    /// Returns how long objects of the given type stay fresh.
+    pub fn ttl(&self, object_type: &str) -> Duration {
+        self.by_type.get(object_type).cloned().unwrap_or(self.default)
+    }
+}
+
+impl Default for CacheTtls {
+
+    fn default() -> Self {
+
+        let minutes = |minutes: u64| Duration::from_secs(minutes * 60);
+
+        Self::uniform(minutes(60))
+            .with("chart", minutes(5))
+            .with("search", minutes(5))
+            .with("playlist", minutes(10))
+            .with("user", minutes(10))
+            .with("artist", minutes(6 * 60))
+            .with("podcast", minutes(6 * 60))
+            .with("album", minutes(24 * 60))
+            .with("track", minutes(24 * 60))
+            .with("episode", minutes(24 * 60))
+            .with("genre", minutes(24 * 60))
+    }
+}
+
+/// A [`Cache`](Cache) keeping up to a given number of responses in memory.
+///
+/// When full, expired entries are dropped first, then the least recently used ones.
+/// Wrap it in an `Arc` to share it between several [`Api`](Api)s.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate deezer_metadata;
+/// # use deezer_metadata::api::Api;
+/// # use deezer_metadata::api::cache::MemoryCache;
+/// # use deezer_metadata::api::transport::MemoryTransport;
+/// # fn main() {
+/// # let transport = MemoryTransport::new()
+/// #     .with_body("https://api.deezer.com/genre/132", r#"{
+/// #         "id": 132, "name": "Pop", "picture": "", "picture_small": "",
+/// #         "picture_medium": "", "picture_big": "", "picture_xl": ""
+/// #     }"#);
+/// let deezer = Api::builder()
+/// #   .transport(transport)
+///     .cache(MemoryCache::new(1000))
+///     .build();
+///
+/// // Only the first call reaches Deezer
+/// let genre1 = deezer.get_genre(132).unwrap();
+/// let genre2 = deezer.get_genre(132).unwrap();
+///
+/// let stats = deezer.cache_stats().unwrap();
+/// assert_eq!((stats.hits, stats.misses, stats.entries), (1, 1, 1));
+/// # assert_eq!(genre1.id, genre2.id);
+/// # }
+/// ```
+///
+/// Answers that don't decode, like the errors Deezer sends with a `200`, aren't kept:
+///
+/// ```rust
+/// # extern crate deezer_metadata;
+/// # use deezer_metadata::api::Api;
+/// # use deezer_metadata::api::cache::MemoryCache;
+/// # use deezer_metadata::api::transport::MemoryTransport;
+/// # fn main() {
+/// # let transport = MemoryTransport::new()
+/// #     .with_body("https://api.deezer.com/genre/132",
+/// #         r#"{"error":{"type":"Exception","message":"Quota limit exceeded","code":4}}"#);
+/// let deezer = Api::builder()
+/// #   .transport(transport)
+///     .cache(MemoryCache::new(1000))
+///     .build();
+///
+/// // Both calls reach Deezer
+/// assert!(deezer.get_genre(132).is_err());
+/// assert!(deezer.get_genre(132).is_err());
+///
+/// let stats = deezer.cache_stats().unwrap();
+/// assert_eq!((stats.hits, stats.misses, stats.entries), (0, 2, 0));
+/// # }
+/// ```
+///
+/// Shortened structs reach the cache through `get_full_with`, unlike `get_full`:
+///
+/// ```rust
+/// # extern crate deezer_metadata;
+/// # use deezer_metadata::api::Api;
+/// # use deezer_metadata::api::cache::MemoryCache;
+/// # use deezer_metadata::api::transport::MemoryTransport;
+/// # fn main() {
+/// # let transport = MemoryTransport::new()
+/// #     .with_body("https://api.deezer.com/genre/132/artists", r#"{"data": [{
+/// #         "id": 27, "name": "Daft Punk", "picture": "", "picture_small": "",
+/// #         "picture_medium": "", "picture_big": "", "picture_xl": "",
+/// #         "radio": true, "tracklist": ""
+/// #     }]}"#)
+/// #     .with_body("https://api.deezer.com/artist/27", r#"{
+/// #         "id": 27, "name": "Daft Punk", "link": "", "share": "", "picture": "",
+/// #         "picture_small": "", "picture_medium": "", "picture_big": "", "picture_xl": "",
+/// #         "nb_album": 36, "nb_fan": 4000000, "radio": true, "tracklist": ""
+/// #     }"#);
+/// let deezer = Api::builder()
+/// #   .transport(transport)
+///     .cache(MemoryCache::new(1000))
+///     .build();
+///
+/// let artist = deezer.get_genre_artists(132).next().unwrap().unwrap();
+///
+/// // Only the first call reaches Deezer
+/// let full1 = artist.get_full_with(&deezer).unwrap();
+/// let full2 = artist.get_full_with(&deezer).unwrap();
+///
+/// let stats = deezer.cache_stats().unwrap();
+/// assert_eq!((stats.hits, stats.misses), (1, 2));
+/// # assert_eq!(full1.nb_fan, full2.nb_fan);
+/// # }
+/// ```
+///
+/// Write requests drop every cached response they make stale:
+///
+/// ```rust
+/// # extern crate deezer_metadata;
+/// # use deezer_metadata::api::Api;
+/// # use deezer_metadata::api::cache::MemoryCache;
+/// # use deezer_metadata::api::transport::{Method, MemoryTransport, Response};
+/// # fn main() {
+/// # let transport = MemoryTransport::new()
+/// #     .with_body("https://api.deezer.com/playlist/908622995?access_token=t0k3n", r#"{
+/// #         "id": 908622995, "title": "Road trip", "description": "", "duration": 0,
+/// #         "public": true, "is_loved_track": false, "collaborative": false, "nb_tracks": 0,
+/// #         "fans": 0, "link": "", "share": "", "picture": "", "picture_small": "",
+/// #         "picture_medium": "", "picture_big": "", "picture_xl": "", "checksum": "",
+/// #         "creator": {"id": 5, "name": "user"}, "tracks": {"data": []}
+/// #     }"#)
+/// #     .with_body("https://api.deezer.com/user/me/playlists?access_token=t0k3n", r#"{"data": [{
+/// #         "id": 908622995, "title": "Road trip", "public": true, "nb_tracks": 0,
+/// #         "link": "", "picture": "", "picture_small": "", "picture_medium": "",
+/// #         "picture_big": "", "picture_xl": "", "checksum": "",
+/// #         "creator": {"id": 5, "name": "user"}
+/// #     }]}"#)
+/// #     .with_response(Method::Post,
+/// #         "https://api.deezer.com/playlist/908622995/tracks?songs=3135556&access_token=t0k3n",
+/// #         Response::new(200, "true"))
+/// #     .with_response(Method::Delete,
+/// #         "https://api.deezer.com/playlist/908622995?access_token=t0k3n",
+/// #         Response::new(200, "true"));
+/// let deezer = Api::builder()
+/// #   .transport(transport)
+///     .access_token("t0k3n")
+///     .cache(MemoryCache::new(1000))
+///     .build();
+///
+/// let misses = || deezer.cache_stats().unwrap().misses;
+///
+/// // Adding tracks changes the playlist, which embeds them
+/// deezer.get_playlist(908622995).unwrap();
+/// deezer.add_playlist_tracks(908622995, &[3135556]).unwrap();
+/// deezer.get_playlist(908622995).unwrap();
+/// assert_eq!(misses(), 2);
+///
+/// // Deleting the playlist changes the list of playlists of the current user
+/// deezer.get_me_playlists().collect::<Result<Vec<_>, _>>().unwrap();
+/// deezer.delete_playlist(908622995).unwrap();
+/// deezer.get_me_playlists().collect::<Result<Vec<_>, _>>().unwrap();
+/// assert_eq!(misses(), 4);
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct MemoryCache {
+    capacity: usize,
+    ttls: CacheTtls,
+    state: Mutex<MemoryState>,
+}
+
+#[derive(Debug, Default)]
+struct MemoryState {
+    entries: HashMap<String, MemoryEntry>,
+
+    /// The urls by when they were last used, least recently used first
+    lru: BTreeMap<u64, String>,
+
+    /// The urls by when they expire, soonest first (ties broken by when they were inserted)
+    expiry: BTreeMap<(Instant, u64), String>,
+
+    stats: CacheStats,
+    clock: u64,
+}
+
+#[derive(Debug)]
+struct MemoryEntry {
+    body: String,
+    expires_at: Instant,
+    inserted: u64,
+    last_used: u64,
+}
+
+impl MemoryCache {
+
+    /// Returns an empty `MemoryCache` holding at most `capacity` responses,
+    /// with the default [`CacheTtls`](CacheTtls).
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate deezer_metadata;
+    /// # use deezer_metadata::api::cache::{Cache, MemoryCache};
+    /// # fn main() {
+    /// let cache = MemoryCache::new(2);
+    ///
+    /// cache.insert("https://api.deezer.com/genre/0", "genre", "All");
+    /// cache.insert("https://api.deezer.com/genre/132", "genre", "Pop");
+    ///
+    /// // `genre/132` is now the least recently used, so it makes room for `genre/116`
+    /// assert!(cache.get("https://api.deezer.com/genre/0").is_some());
+    /// cache.insert("https://api.deezer.com/genre/116", "genre", "Rap/Hip Hop");
+    ///
+    /// assert_eq!(cache.get("https://api.deezer.com/genre/132"), None);
+    /// assert_eq!(cache.get("https://api.deezer.com/genre/0").unwrap(), "All");
+    /// assert_eq!(cache.stats().evictions, 1);
+    /// # }
+    /// ```
+    pub fn new(capacity: usize) -> Self {
+        Self::with_ttls(capacity, CacheTtls::default())
+    }
+
+    /// Returns an empty `MemoryCache` holding at most `capacity` responses,
+    /// keeping them for the given times.
+    pub fn with_ttls(capacity: usize, ttls: CacheTtls) -> Self {
+
+        MemoryCache {
+            capacity,
+            ttls,
+            state: Mutex::new(MemoryState::default()),
+        }
+    }
+
+    /// Drops every cached response, keeping the statistics.
+    pub fn clear(&self) {
+        let mut state = self.state();
+
+        state.entries.clear();
+        state.lru.clear();
+        state.expiry.clear();
+        state.stats.entries = 0;
+    }
+
+    /// A panic while the lock was held can't leave the state half updated, so it's safe to reuse.
+    fn state(&self) -> MutexGuard<'_, MemoryState> {
+        self.state.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+impl MemoryState {
+
+    /// Removes the entry of the url from the map and from both orders.
+    fn remove(&mut self, url: &str) -> Option<MemoryEntry> {
+        let entry = self.entries.remove(url)?;
+
+        self.lru.remove(&entry.last_used);
+        self.expiry.remove(&(entry.expires_at, entry.inserted));
+
+        Some(entry)
+    }
+
+    /// Makes room for one more entry, without going through every entry.
+    fn evict(&mut self, capacity: usize) {
+
+        let now = Instant::now();
+
+        loop {
+            let url = match self.expiry.first_key_value() {
+                Some((&(expires_at, _), url)) if expires_at <= now => url.clone(),
+                _ => break,
+            };
+
+            self.remove(&url);
+        }
+
+        while self.entries.len() >= capacity.max(1) {
+            let url = match self.lru.first_key_value() {
+                Some((_, url)) => url.clone(),
+                None => break,
+            };
+
+            self.remove(&url);
+            self.stats.evictions += 1;
+        }
+    }
+}
+
+impl Cache for MemoryCache {
+
+    fn get(&self, url: &str) -> Option<String> {
+
+        let mut state = self.state();
+        state.clock += 1;
+
+        let clock = state.clock;
+        let now = Instant::now();
+
+        let (body, last_used) = match state.entries.get_mut(url) {
+            Some(ref mut entry) if entry.expires_at > now => {
+                let last_used = entry.last_used;
+                entry.last_used = clock;
+
+                (Some(entry.body.clone()), Some(last_used))
+            },
+            Some(_) => {
+                state.remove(url);
+                (None, None)
+            },
+            None => (None, None),
+        };
+
+        if let Some(last_used) = last_used {
+            state.lru.remove(&last_used);
+            state.lru.insert(clock, url.to_owned());
+        }
+
+        match body {
+            Some(_) => state.stats.hits += 1,
+            None => state.stats.misses += 1,
+        }
+
+        state.stats.entries = state.entries.len() as u64;
+        body
+    }
+
+    fn insert(&self, url: &str, object_type: &str, body: &str) {
+
+        if self.capacity == 0 {
+            return;
+        }
+
+        let mut state = self.state();
+        state.clock += 1;
+
+        if state.remove(url).is_none() {
+            state.evict(self.capacity);
+        }
+
+        let clock = state.clock;
+        let entry = MemoryEntry {
+            body: body.to_owned(),
+            expires_at: Instant::now() + self.ttls.ttl(object_type),
+            inserted: clock,
+            last_used: clock,
+        };
+
+        state.lru.insert(clock, url.to_owned());
+        state.expiry.insert((entry.expires_at, clock), url.to_owned());
+        state.entries.insert(url.to_owned(), entry);
+        state.stats.entries = state.entries.len() as u64;
+    }
+
+    fn invalidate(&self, url: &str) {
+        let mut state = self.state();
+
+        let stale: Vec<String> = state.entries.keys()
+            .filter(|cached| is_below(cached, url))
+            .cloned()
+            .collect();
+
+        for cached in stale {
+            state.remove(&cached);
+        }
+
+        state.stats.entries = state.entries.len() as u64;
+    }
+
+    fn stats(&self) -> CacheStats {
+        self.state().stats
+    }
+}
+
+/// Whether `url` is `parent`, or a url below it (`parent/...` or `parent?...`).
+pub(crate) fn is_below(url: &str, parent: &str) -> bool {
+    match url.strip_prefix(parent) {
+        Some(rest) => rest.is_empty() || rest.starts_with('/') || rest.starts_with('?'),
+        None => false,
+    }
+}
@@ -0,0 +1,231 @@
+//! Contains the [`DeezerError`](DeezerError) enum.
+//! Also contains the [`ApiError`](ApiError) helper struct.
+#![deny(warnings, missing_docs)]
+
+use std::error::Error;
+use std::fmt;
+use std::io;
+
+use api::transport::redact_url;
+
+/// Everything that can go wrong while fetching an object from Deezer.
+///
+/// Every fetcher in this crate returns a `Result<T, DeezerError>`, so a single
+/// bad id or a network hiccup can be handled instead of crashing the caller.
+///
+/// # Examples
+///
+/// Deezer's error payloads are mapped to a variant based on their code:
+///
+/// ```rust
+/// # extern crate deezer_metadata;
+/// # use deezer_metadata::api::Api;
+/// # use deezer_metadata::api::errors::DeezerError;
+/// # use deezer_metadata::api::transport::MemoryTransport;
+/// # fn main() {
+/// let get_track = |error: &str| {
+///     let transport = MemoryTransport::new()
+///         .with_body("https://api.deezer.com/track/1", &format!(r#"{{"error":{}}}"#, error));
+///
+///     Api::with_transport(transport).get_track(1)
+/// };
+///
+/// match get_track(r#"{"type":"DataException","message":"no data","code":800}"#) {
+///     Err(DeezerError::NotFound(e)) => assert_eq!(e.message, "no data"),
+///     other => panic!("expected NotFound, got {:?}", other),
+/// }
+///
+/// match get_track(r#"{"type":"Exception","message":"Quota limit exceeded","code":4}"#) {
+///     Err(DeezerError::QuotaExceeded(e)) => assert_eq!(e.code, 4),
+///     other => panic!("expected QuotaExceeded, got {:?}", other),
+/// }
+///
+/// match get_track(r#"{"type":"MissingParameterException","message":"Missing parameters","code":501}"#) {
+///     Err(DeezerError::InvalidParameter(e)) => assert_eq!(e.code, 501),
+///     other => panic!("expected InvalidParameter, got {:?}", other),
+/// }
+///
+/// match get_track(r#"{"type":"OAuthException","message":"Invalid OAuth access token.","code":300}"#) {
+///     Err(DeezerError::OAuth(e)) => assert_eq!(e.error_type, "OAuthException"),
+///     other => panic!("expected OAuth, got {:?}", other),
+/// }
+/// # }
+/// ```
+#[derive(Debug)]
+pub enum DeezerError {
+
+    /// The request could not be sent, or its response could not be read.
+    Transport(Box<dyn Error + Send + Sync>),
+
+    /// Deezer answered with a non successful HTTP status code.
+    HttpStatus(u16),
+
+    /// The response body could not be decoded into the expected struct.
+    Json {
+
+        /// Path to the field that failed to decode (e.g. `tracks.data[3].id`)
+        path: String,
+
+        /// The underlying decoding error
+        source: serde_json::Error,
+    },
+
+    /// The requested object doesn't exist (Deezer error code 800).
+    NotFound(ApiError),
+
+    /// Deezer answered with error code 801, which its documentation doesn't list but which is
+    /// assumed to mean the object to add already exists.
+    AlreadyExists(ApiError),
+
+    /// Too many requests were made in a short time (Deezer error code 4).
+    QuotaExceeded(ApiError),
+
+    /// A parameter of the request was missing or invalid (Deezer error codes 500 to 599).
+    InvalidParameter(ApiError),
+
+    /// The access token is missing, invalid or expired (Deezer error code 300).
+    OAuth(ApiError),
+
+    /// The access token lacks the permission the request needs (Deezer error code 200),
+    /// e.g. `manage_library` to change favorites.
+    PermissionDenied(ApiError),
+
+    /// Deezer answered with any other error payload.
+    Api(ApiError),
+
+    /// A code passed to a lookup isn't well formed, so no request was sent.
+    InvalidCode {
+
+        /// The kind of code (e.g. `ISRC` or `UPC`)
+        kind: &'static str,
+
+        /// The code as it was given
+        code: String,
+    },
+
+    /// A link doesn't point to any Deezer object.
+    InvalidLink(String),
+
+    /// A link points to a Deezer object whose id doesn't fit in the ids this crate uses for its type
+    /// (`u32` for every type but playlists).
+    IdOutOfRange {
+
+        /// The link as it was given
+        link: String,
+
+        /// The id found in the link
+        id: String,
+    },
+
+    /// The cache is offline only and can't answer the request to the given url.
+    Offline(String),
+
+    /// The on-disk cache could not be set up.
+    Cache(io::Error),
+}
+
+impl fmt::Display for DeezerError {
+
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            DeezerError::Transport(ref e) => write!(f, "transport error: {}", e),
+            DeezerError::HttpStatus(status) => write!(f, "unexpected HTTP status: {}", status),
+            DeezerError::Json { ref path, ref source } => {
+                write!(f, "could not decode response at `{}`: {}", path, source)
+            },
+            DeezerError::NotFound(ref e) => write!(f, "not found: {}", e),
+            DeezerError::AlreadyExists(ref e) => write!(f, "already exists: {}", e),
+            DeezerError::QuotaExceeded(ref e) => write!(f, "quota exceeded: {}", e),
+            DeezerError::InvalidParameter(ref e) => write!(f, "invalid parameter: {}", e),
+            DeezerError::OAuth(ref e) => write!(f, "oauth error: {}", e),
+            DeezerError::PermissionDenied(ref e) => write!(f, "permission denied: {}", e),
+            DeezerError::Api(ref e) => write!(f, "api error: {}", e),
+            DeezerError::InvalidCode { kind, ref code } => write!(f, "invalid {}: `{}`", kind, code),
+            DeezerError::InvalidLink(ref link) => write!(f, "invalid Deezer link: `{}`", link),
+            DeezerError::IdOutOfRange { ref link, ref id } => {
+                write!(f, "id `{}` of Deezer link `{}` is out of range", id, link)
+            },
+            DeezerError::Offline(ref url) => write!(f, "not in the offline cache: {}", url),
+            DeezerError::Cache(ref e) => write!(f, "cache error: {}", e),
+        }
+    }
+}
+
+impl Error for DeezerError {
+
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match *self {
+            DeezerError::Transport(ref e) => Some(&**e),
+            DeezerError::Json { ref source, .. } => Some(source),
+            DeezerError::Cache(ref e) => Some(e),
+            DeezerError::NotFound(ref e)
+            | DeezerError::AlreadyExists(ref e)
+            | DeezerError::QuotaExceeded(ref e)
+            | DeezerError::InvalidParameter(ref e)
+            | DeezerError::OAuth(ref e)
+            | DeezerError::PermissionDenied(ref e)
+            | DeezerError::Api(ref e) => Some(e),
+            DeezerError::HttpStatus(_)
+            | DeezerError::InvalidCode { .. }
+            | DeezerError::InvalidLink(_)
+            | DeezerError::IdOutOfRange { .. }
+            | DeezerError::Offline(_) => None,
+        }
+    }
+}
+
+impl From<reqwest::Error> for DeezerError {
+
+    /// Keeps the message only, with the secrets of the url reqwest puts in it masked.
+    fn from(e: reqwest::Error) -> Self {
+        let message = match e.url() {
+            Some(url) => e.to_string().replace(url.as_str(), &redact_url(url.as_str())),
+            None => e.to_string(),
+        };
+
+        DeezerError::Transport(message.into())
+    }
+}
+
+impl From<ApiError> for DeezerError {
+
+    /// Maps the error payload to the matching variant based on its code.
+    fn from(e: ApiError) -> Self {
+        match e.code {
+            800 => DeezerError::NotFound(e),
+            801 => DeezerError::AlreadyExists(e),
+            4 => DeezerError::QuotaExceeded(e),
+            500..=599 => DeezerError::InvalidParameter(e),
+            200 => DeezerError::PermissionDenied(e),
+            300 => DeezerError::OAuth(e),
+            _ if e.error_type == "OAuthException" => DeezerError::OAuth(e),
+            _ => DeezerError::Api(e),
+        }
+    }
+}
+
+/// The error payload Deezer sends back, e.g.
+/// `{"error":{"type":"DataException","message":"no data","code":800}}`.
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+pub struct ApiError {
+
+    /// The exception type reported by Deezer (e.g. `DataException`)
+    #[serde(rename = "type")]
+    pub error_type: String,
+
+    /// The human readable message
+    pub message: String,
+
+    /// Deezer's error code
+    #[serde(default)]
+    pub code: u32,
+}
+
+impl fmt::Display for ApiError {
+
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{} ({}): {}", self.error_type, self.code, self.message)
+    }
+}
+
+impl Error for ApiError {}
@@ -1,156 +1,1236 @@
-#[allow(dead_code)]
+#![allow(dead_code)]
 
+#[cfg(feature = "async")]
+pub mod asynchronous;
+pub mod cache;
+pub mod errors;
+pub mod oauth;
 pub mod objects;
+pub mod transport;
 
-use reqwest::Client;
+#[cfg(feature = "async")]
+pub use self::asynchronous::AsyncApi;
 
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+
+use url::form_urlencoded;
+
+use self::cache::{
+    Cache,
+    CacheStats,
+};
+use self::errors::DeezerError;
+use self::oauth::DEFAULT_CONNECT_URL;
 use self::objects::*;
+use self::transport::{
+    Method,
+    ReqwestTransport,
+    Request,
+    Transport,
+};
+
+/// The url of Deezer's public API.
+pub const DEFAULT_BASE_URL: &str = "https://api.deezer.com";
 
-pub struct Api {
-    client: Client,
+/// Client for Deezer's public API.
+///
+/// Sends every request through a [`Transport`](Transport), which is a
+/// [`ReqwestTransport`](ReqwestTransport) unless another one is given.
+/// Use an [`ApiBuilder`](ApiBuilder) to configure it.
+///
+/// Responses are only kept once a [`Cache`](Cache) is given.
+pub struct Api<T = ReqwestTransport> {
+    base_url: String,
+    connect_url: String,
+    access_token: Option<String>,
+    cache: Option<Box<dyn Cache + Send + Sync>>,
+    transport: T,
 }
 
 impl Api {
 
+    /// Returns an `Api` using the default [`ReqwestTransport`](ReqwestTransport).
+    pub fn new() -> Self {
+        Self::with_transport(ReqwestTransport::new())
+    }
+
+    /// Returns an [`ApiBuilder`](ApiBuilder) to configure a new `Api`.
+    pub fn builder() -> ApiBuilder {
+        ApiBuilder::new()
+    }
+}
+
+impl Default for Api {
+
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Transport> Api<T> {
+
+    /// Returns an `Api` sending every request through the given transport.
+    pub fn with_transport(transport: T) -> Self {
+
+        Api {
+            base_url: DEFAULT_BASE_URL.to_owned(),
+            connect_url: DEFAULT_CONNECT_URL.to_owned(),
+            access_token: None,
+            cache: None,
+            transport,
+        }
+    }
+
+    /// Makes every request on behalf of the user the access token belongs to.
+    ///
+    /// See [`AccessToken`](oauth::AccessToken) to get one.
+    pub fn with_access_token(mut self, access_token: &str) -> Self {
+        self.access_token = Some(access_token.to_owned());
+        self
+    }
+
+    /// Returns the transport this `Api` sends its requests through.
+    pub fn transport(&self) -> &T {
+        &self.transport
+    }
+
+    /// Returns the url every api path is appended to.
+    pub fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    /// Keeps the responses of `GET` requests in the given cache.
+    ///
+    /// The `get_*`, `search_*` and paginated methods of this `Api` go through the cache,
+    /// as do the `get_full_with` methods of the shortened structs when given this `Api`.
+    /// `Track::get`, `get_full` and the other shortcuts create a new `Api` on every call,
+    /// so they never hit it. Write requests, OAuth and short links aren't cached.
+    ///
+    /// See [`MemoryCache`](cache::MemoryCache).
+    pub fn with_cache<C: Cache + Send + Sync + 'static>(mut self, cache: C) -> Self {
+        self.cache = Some(Box::new(cache));
+        self
+    }
+
+    /// Returns how the cache performed so far, if there is one.
+    pub fn cache_stats(&self) -> Option<CacheStats> {
+        self.cache.as_ref().map(|cache| cache.stats())
+    }
+
+    /// Returns the url of the OAuth host.
+    pub fn connect_url(&self) -> &str {
+        &self.connect_url
+    }
+
+    /// Returns the access token requests are made with, if any.
+    pub fn access_token(&self) -> Option<&str> {
+        self.access_token.as_deref()
+    }
+
+    /// Joins an api path (e.g. `track/3135556`) to the base url.
+    fn url(&self, path: &str) -> String {
+        format!("{}/{}", self.base_url, path)
+    }
+
+    /// Points a url Deezer handed out (e.g. a `next` link) at the base url.
+    fn rebase_url(&self, url: &str) -> String {
+
+        for deezer in &[DEFAULT_BASE_URL, "http://api.deezer.com"] {
+            if let Some(path) = url.strip_prefix(deezer) {
+                return format!("{}{}", self.base_url, path);
+            }
+        }
+
+        url.to_owned()
+    }
+
+    /// Fetches and decodes the body behind the given api path.
+    fn fetch<U, F>(&self, path: &str, decode: F) -> Result<U, DeezerError>
+        where F: FnOnce(&str) -> Result<U, DeezerError>
+    {
+        self.fetch_url(&self.url(path), decode)
+    }
+
+    /// Adds the access token, if any, to the query string of the url.
+    fn authenticate_url(&self, url: &str) -> String {
+
+        match self.access_token {
+            Some(ref token) if !url.contains("access_token=") => {
+                let separator = if url.contains('?') { '&' } else { '?' };
+                let token: String = form_urlencoded::byte_serialize(token.as_bytes()).collect();
+
+                format!("{}{}access_token={}", url, separator, token)
+            },
+            _ => url.to_owned(),
+        }
+    }
+
+    /// Returns the type of object behind a url (e.g. `album`), the first segment of its path.
+    fn object_type<'u>(&self, url: &'u str) -> &'u str {
+        url.strip_prefix(self.base_url.as_str())
+            .unwrap_or(url)
+            .trim_start_matches('/')
+            .split(&['/', '?'][..])
+            .next()
+            .unwrap_or("")
+    }
+
+    /// Fetches and decodes the body behind the given url, from the cache if possible.
+    fn fetch_url<U, F>(&self, url: &str, decode: F) -> Result<U, DeezerError>
+        where F: FnOnce(&str) -> Result<U, DeezerError>
+    {
+
+        let cache = match self.cache {
+            Some(ref cache) => cache,
+            None => return decode(&self.send(Method::Get, url)?),
+        };
+
+        let key = self.authenticate_url(url);
+
+        if let Some(body) = cache.get(&key) {
+            return decode(&body);
+        }
+
+        if cache.is_offline() {
+            return Err(DeezerError::Offline(url.to_owned()));
+        }
+
+        let body = self.send(Method::Get, url)?;
+
+        // Deezer answers errors (e.g. an exceeded quota) with a 200, only bodies that
+        // decode are worth keeping
+        let decoded = decode(&body)?;
+        cache.insert(&key, self.object_type(url), &body);
+
+        Ok(decoded)
+    }
+
+    /// Sends a write request to the given api path, with the given parameters
+    /// in the query string, and returns the body.
+    ///
+    /// Drops the cached responses of the `stale` api paths (and of the ones below them),
+    /// which the write may have changed.
+    fn write(&self, method: Method, path: &str, params: &[(&str, String)], stale: &[&str])
+        -> Result<String, DeezerError>
+    {
+
+        let mut url = self.url(path);
+
+        if !params.is_empty() {
+            let query = form_urlencoded::Serializer::new(String::new())
+                .extend_pairs(params.iter().map(|&(name, ref value)| (name, value)))
+                .finish();
+
+            url = format!("{}?{}", url, query);
+        }
+
+        self.check_online(&url)?;
+
+        // Whether the request succeeds or not, cached copies may now be stale
+        if let Some(ref cache) = self.cache {
+            for stale in stale {
+                cache.invalidate(&self.url(stale));
+            }
+        }
+
+        self.send(method, &url)
+    }
+
+    /// Adds the object with the given id to a favorites list of the current user
+    /// (e.g. `artists` with `artist_id`), succeeding if it already was in it.
+    fn add_favorite(&self, relation: &str, param: &'static str, id: u64) -> Result<(), DeezerError> {
+        let path = user::get_me_relation_path(relation);
+        let result = self.write(Method::Post, &path, &[(param, id.to_string())], &[&path])
+            .and_then(|json| objects::from_json_ack(&json));
+
+        // Deezer doesn't document its answer for objects already in the list, so any refusal
+        // that isn't about the token is checked against the list itself
+        match result {
+            Err(DeezerError::AlreadyExists(_))
+            | Err(DeezerError::InvalidParameter(_))
+            | Err(DeezerError::Api(_)) if self.is_favorite(relation, id).unwrap_or(false) => Ok(()),
+            result => result,
+        }
+    }
+
+    /// Removes the object with the given id from a favorites list of the current user,
+    /// succeeding if it wasn't in it as long as the object exists.
+    fn remove_favorite(&self, relation: &str, param: &'static str, id: u64) -> Result<(), DeezerError> {
+        let path = user::get_me_relation_path(relation);
+        let result = self.write(Method::Delete, &path, &[(param, id.to_string())], &[&path])
+            .and_then(|json| objects::from_json_ack(&json));
+
+        // Deezer answers 800 (no data) both when the object isn't in the list and when it
+        // doesn't exist at all, only the former is a success
+        match result {
+            Err(DeezerError::NotFound(_)) => {
+                let object = format!("{}/{}", param.trim_end_matches("_id"), id);
+
+                self.fetch(&object, objects::from_json::<Value>).map(|_| ())
+            },
+            result => result,
+        }
+    }
+
+    /// Tells whether the object with the given id is in a favorites list of the current user.
+    fn is_favorite(&self, relation: &str, id: u64) -> Result<bool, DeezerError> {
+        for favorite in self.paginate::<Value>(&user::get_me_relation_path(relation)) {
+            if favorite?.get("id").and_then(Value::as_u64) == Some(id) {
+                return Ok(true);
+            }
+        }
+
+        Ok(false)
+    }
+
+    /// Fails if the cache is offline only, for requests it can't answer.
+    fn check_online(&self, url: &str) -> Result<(), DeezerError> {
+
+        match self.cache {
+            Some(ref cache) if cache.is_offline() => Err(DeezerError::Offline(url.to_owned())),
+            _ => Ok(()),
+        }
+    }
+
+    /// Sends a request with the given method to the given url and returns the body.
+    fn send(&self, method: Method, url: &str) -> Result<String, DeezerError> {
+        let resp = self.transport.send(&Request {
+            method,
+            url: self.authenticate_url(url),
+        })?;
+
+        if !(200..300).contains(&resp.status) {
+            return Err(DeezerError::HttpStatus(resp.status));
+        }
+
+        Ok(resp.body)
+    }
+
+    /// Lazily walks every object of the list behind the given api path
+    /// (e.g. `playlist/908622995/tracks`), following the `next` links.
+    ///
+    /// See [`Paginated`](page::Paginated).
+    pub fn paginate<U: DeserializeOwned>(&self, path: &str) -> page::Paginated<'_, T, U> {
+        page::Paginated::new(self, Some(self.url(path)), Vec::new())
+    }
+
+    /// Lazily walks the objects of an already fetched page, then the ones of
+    /// every following page.
+    pub fn paginate_from<U: DeserializeOwned>(&self, page: page::Page<U>) -> page::Paginated<'_, T, U> {
+        let next = page.next.map(|next| self.rebase_url(&next));
+
+        page::Paginated::new(self, next, page.data)
+    }
+}
+
+/// Configures an [`Api`](Api).
+///
+/// # Examples
+///
+/// Point the client at a local stand-in for Deezer:
+///
+/// ```rust
+/// # extern crate deezer_metadata;
+/// # use deezer_metadata::api::Api;
+/// # use deezer_metadata::api::transport::MemoryTransport;
+/// # fn main() {
+/// # let transport = MemoryTransport::new()
+/// #     .with_body("http://localhost:8080/genre/0", r#"{
+/// #         "id": 0, "name": "All", "picture": "", "picture_small": "",
+/// #         "picture_medium": "", "picture_big": "", "picture_xl": ""
+/// #     }"#);
+/// let deezer = Api::builder()
+///     .base_url("http://localhost:8080/")
+/// #   .transport(transport)
+///     .build();
+///
+/// // Requests `http://localhost:8080/genre/0`
+/// let genre = deezer.get_genre(0).unwrap();
+/// # assert_eq!(genre.name, "All");
+/// # }
+/// ```
+pub struct ApiBuilder<T = ReqwestTransport> {
+    base_url: String,
+    connect_url: String,
+    access_token: Option<String>,
+    cache: Option<Box<dyn Cache + Send + Sync>>,
+    transport: T,
+}
+
+impl ApiBuilder {
+
+    /// Returns an `ApiBuilder` targeting [`DEFAULT_BASE_URL`](DEFAULT_BASE_URL)
+    /// through a [`ReqwestTransport`](ReqwestTransport).
     pub fn new() -> Self {
 
+        ApiBuilder {
+            base_url: DEFAULT_BASE_URL.to_owned(),
+            connect_url: DEFAULT_CONNECT_URL.to_owned(),
+            access_token: None,
+            cache: None,
+            transport: ReqwestTransport::new(),
+        }
+    }
+}
+
+impl Default for ApiBuilder {
+
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Transport> ApiBuilder<T> {
+
+    /// Sets the url every api path is appended to (e.g. a local mock server).
+    pub fn base_url(mut self, base_url: &str) -> Self {
+        self.base_url = base_url.trim_end_matches('/').to_owned();
+        self
+    }
+
+    /// Sets the url of the OAuth host (e.g. a local mock server).
+    pub fn connect_url(mut self, connect_url: &str) -> Self {
+        self.connect_url = connect_url.trim_end_matches('/').to_owned();
+        self
+    }
+
+    /// Sets the access token requests are made with.
+    pub fn access_token(mut self, access_token: &str) -> Self {
+        self.access_token = Some(access_token.to_owned());
+        self
+    }
+
+    /// Sets the cache the responses of `GET` requests are kept in.
+    ///
+    /// See [`Api::with_cache`](Api::with_cache) for the calls it applies to.
+    pub fn cache<C: Cache + Send + Sync + 'static>(mut self, cache: C) -> Self {
+        self.cache = Some(Box::new(cache));
+        self
+    }
+
+    /// Sets the transport requests are sent through.
+    pub fn transport<U: Transport>(self, transport: U) -> ApiBuilder<U> {
+
+        ApiBuilder {
+            base_url: self.base_url,
+            connect_url: self.connect_url,
+            access_token: self.access_token,
+            cache: self.cache,
+            transport,
+        }
+    }
+
+    /// Returns the configured [`Api`](Api).
+    pub fn build(self) -> Api<T> {
+
         Api {
-            client: Client::new(),
+            base_url: self.base_url,
+            connect_url: self.connect_url,
+            access_token: self.access_token,
+            cache: self.cache,
+            transport: self.transport,
         }
     }
 }
 
-impl Api {
+impl<T: Transport> Api<T> {
 
     /// Returns the [`Track`](Track) with the given id.
-    pub fn get_track(&self, id: u32) -> track::Track {
-        let json = self.client.get(&track::get_track_api(id))
-            .send()
-            .unwrap()
-            .text()
-            .unwrap();
+    pub fn get_track(&self, id: u32) -> Result<track::Track, DeezerError> {
+        self.fetch(&track::get_track_path(id), track::Track::new)
+    }
 
-        track::Track::new(&json)
+    /// Returns the [`Track`](Track) with the given ISRC.
+    ///
+    /// Fails with [`DeezerError::InvalidCode`] without sending a request if the
+    /// ISRC is malformed, and with [`DeezerError::NotFound`] if Deezer doesn't know it.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate deezer_metadata;
+    /// # use deezer_metadata::api::Api;
+    /// # use deezer_metadata::api::errors::DeezerError;
+    /// # use deezer_metadata::api::transport::MemoryTransport;
+    /// # fn main() {
+    /// let transport = MemoryTransport::new()
+    ///     .with_body("https://api.deezer.com/track/isrc:GBDUW0000059",
+    ///         r#"{"error":{"type":"DataException","message":"no data","code":800}}"#);
+    ///
+    /// let deezer = Api::with_transport(transport);
+    ///
+    /// // Dashes and case are ignored
+    /// match deezer.get_track_by_isrc(" gb-duw-00-00059 ") {
+    ///     Err(DeezerError::NotFound(_)) => {},
+    ///     other => panic!("expected NotFound, got {:?}", other),
+    /// }
+    ///
+    /// // Malformed codes never reach Deezer
+    /// for isrc in &["GBDUW000005", "GBDUW00000590", "12DUW0000059", "GBDUWAA00059", ""] {
+    ///     match deezer.get_track_by_isrc(isrc) {
+    ///         Err(DeezerError::InvalidCode { kind: "ISRC", ref code }) => assert_eq!(code, isrc),
+    ///         other => panic!("expected InvalidCode, got {:?}", other),
+    ///     }
+    /// }
+    /// # }
+    /// ```
+    pub fn get_track_by_isrc(&self, isrc: &str) -> Result<track::Track, DeezerError> {
+        self.fetch(&track::get_track_by_isrc_path(isrc)?, track::Track::new)
     }
 
     /// Returns the [`Artist`](Artist) with the given id.
-    pub fn get_artist(&self, id: u32) -> artist::Artist {
-        let json = self.client.get(&artist::get_artist_api(id))
-            .send()
-            .unwrap()
-            .text()
-            .unwrap();
+    pub fn get_artist(&self, id: u32) -> Result<artist::Artist, DeezerError> {
+        self.fetch(&artist::get_artist_path(id), artist::Artist::new)
+    }
+
+    /// Returns the comments posted on the [`Artist`](Artist) with the given id.
+    pub fn get_artist_comments(&self, id: u32) -> page::Paginated<'_, T, comment::Comment> {
+        self.paginate(&artist::get_artist_relation_path(id, "comments"))
+    }
+
+    /// Returns the top tracks of the [`Artist`](Artist) with the given id.
+    pub fn get_artist_top(&self, id: u32) -> page::Paginated<'_, T, artist::ArtistTrack> {
+        self.paginate(&artist::get_artist_relation_path(id, "top"))
+    }
+
+    /// Returns the albums of the [`Artist`](Artist) with the given id.
+    pub fn get_artist_albums(&self, id: u32) -> page::Paginated<'_, T, artist::ArtistAlbum> {
+        self.paginate(&artist::get_artist_relation_path(id, "albums"))
+    }
+
+    /// Returns the artists related to the [`Artist`](Artist) with the given id.
+    pub fn get_artist_related(&self, id: u32) -> page::Paginated<'_, T, search::SearchArtist> {
+        self.paginate(&artist::get_artist_relation_path(id, "related"))
+    }
 
-        artist::Artist::new(&json)
+    /// Returns the tracks of the radio of the [`Artist`](Artist) with the given id.
+    pub fn get_artist_radio(&self, id: u32) -> page::Paginated<'_, T, artist::ArtistTrack> {
+        self.paginate(&artist::get_artist_relation_path(id, "radio"))
+    }
+
+    /// Returns the playlists containing the [`Artist`](Artist) with the given id.
+    pub fn get_artist_playlists(&self, id: u32) -> page::Paginated<'_, T, chart::ChartPlaylist> {
+        self.paginate(&artist::get_artist_relation_path(id, "playlists"))
+    }
+
+    /// Returns the fans of the [`Artist`](Artist) with the given id.
+    pub fn get_artist_fans(&self, id: u32) -> page::Paginated<'_, T, comment::CommentAuthor> {
+        self.paginate(&artist::get_artist_relation_path(id, "fans"))
     }
 
     /// Returns the [`Album`](Album) with the given id.
-    pub fn get_album(&self, id: u32) -> album::Album {
-        let json = self.client.get(&album::get_album_api(id))
-            .send()
-            .unwrap()
-            .text()
-            .unwrap();
+    pub fn get_album(&self, id: u32) -> Result<album::Album, DeezerError> {
+        self.fetch(&album::get_album_path(id), album::Album::new)
+    }
 
-        album::Album::new(&json)
+    /// Returns the [`Album`](Album) with the given UPC.
+    ///
+    /// Fails with [`DeezerError::InvalidCode`] without sending a request if the
+    /// UPC is malformed, and with [`DeezerError::NotFound`] if Deezer doesn't know it.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate deezer_metadata;
+    /// # use deezer_metadata::api::Api;
+    /// # use deezer_metadata::api::errors::DeezerError;
+    /// # use deezer_metadata::api::transport::MemoryTransport;
+    /// # fn main() {
+    /// let not_found = r#"{"error":{"type":"DataException","message":"no data","code":800}}"#;
+    /// let transport = MemoryTransport::new()
+    ///     .with_body("https://api.deezer.com/album/upc:724384960650", not_found)
+    ///     .with_body("https://api.deezer.com/album/upc:0724384960650", not_found);
+    ///
+    /// let deezer = Api::with_transport(transport);
+    ///
+    /// // Both UPC-A (12 digits) and EAN-13 (13 digits) codes are looked up
+    /// for upc in &["724384960650", "0724384960650"] {
+    ///     match deezer.get_album_by_upc(upc) {
+    ///         Err(DeezerError::NotFound(_)) => {},
+    ///         other => panic!("expected NotFound, got {:?}", other),
+    ///     }
+    /// }
+    ///
+    /// // Malformed codes never reach Deezer
+    /// for upc in &["72438496065", "00724384960650", "72438496065A", "7243-8496-0650", ""] {
+    ///     match deezer.get_album_by_upc(upc) {
+    ///         Err(DeezerError::InvalidCode { kind: "UPC", ref code }) => assert_eq!(code, upc),
+    ///         other => panic!("expected InvalidCode, got {:?}", other),
+    ///     }
+    /// }
+    /// # }
+    /// ```
+    pub fn get_album_by_upc(&self, upc: &str) -> Result<album::Album, DeezerError> {
+        self.fetch(&album::get_album_by_upc_path(upc)?, album::Album::new)
+    }
+
+    /// Returns the comments posted on the [`Album`](Album) with the given id.
+    pub fn get_album_comments(&self, id: u32) -> page::Paginated<'_, T, comment::Comment> {
+        self.paginate(&album::get_album_relation_path(id, "comments"))
+    }
+
+    /// Returns every track of the [`Album`](Album) with the given id, with
+    /// their disk number and position.
+    ///
+    /// Walks every page of the tracklist, so multi-disc albums are complete.
+    pub fn get_album_tracks(&self, id: u32) -> Result<Vec<album::AlbumTracklistTrack>, DeezerError> {
+        self.paginate(&album::get_album_relation_path(id, "tracks")).collect()
+    }
+
+    /// Returns the fans of the [`Album`](Album) with the given id.
+    pub fn get_album_fans(&self, id: u32) -> page::Paginated<'_, T, comment::CommentAuthor> {
+        self.paginate(&album::get_album_relation_path(id, "fans"))
     }
 
     /// Returns the [`Genre`](Genre) with the given id.
-    pub fn get_genre(&self, id: u32) -> genre::Genre {
-        let json = self.client.get(&genre::get_genre_api(id))
-            .send()
-            .unwrap()
-            .text()
-            .unwrap();
+    pub fn get_genre(&self, id: u32) -> Result<genre::Genre, DeezerError> {
+        self.fetch(&genre::get_genre_path(id), genre::Genre::new)
+    }
 
-        genre::Genre::new(&json)
+    /// Returns every [`Genre`](Genre) available on Deezer.
+    pub fn get_genres(&self) -> Result<Vec<genre::Genre>, DeezerError> {
+        self.paginate(&genre::get_genres_path()).collect()
     }
 
-    /// Returns the [`Comment`](Comment) with the given id.
-    pub fn get_comment(&self, id: u32) -> comment::Comment {
-        let json = self.client.get(&comment::get_comment_api(id))
-            .send()
-            .unwrap()
-            .text()
-            .unwrap();
+    /// Returns the artists of the [`Genre`](Genre) with the given id.
+    pub fn get_genre_artists(&self, id: u32) -> page::Paginated<'_, T, genre::GenreArtist> {
+        self.paginate(&genre::get_genre_relation_path(id, "artists"))
+    }
 
-        comment::Comment::new(&json)
+    /// Returns the radios of the [`Genre`](Genre) with the given id.
+    pub fn get_genre_radios(&self, id: u32) -> page::Paginated<'_, T, search::SearchRadio> {
+        self.paginate(&genre::get_genre_relation_path(id, "radios"))
+    }
+
+    /// Returns the podcasts of the [`Genre`](Genre) with the given id.
+    pub fn get_genre_podcasts(&self, id: u32) -> page::Paginated<'_, T, genre::GenrePodcast> {
+        self.paginate(&genre::get_genre_relation_path(id, "podcasts"))
+    }
+
+    /// Returns the [`Podcast`](Podcast) with the given id.
+    pub fn get_podcast(&self, id: u32) -> Result<podcast::Podcast, DeezerError> {
+        self.fetch(&podcast::get_podcast_path(id), podcast::Podcast::new)
+    }
+
+    /// Returns the episodes of the [`Podcast`](Podcast) with the given id.
+    pub fn get_podcast_episodes(&self, id: u32) -> page::Paginated<'_, T, podcast::PodcastEpisode> {
+        self.paginate(&podcast::get_podcast_relation_path(id, "episodes"))
+    }
+
+    /// Returns the [`Episode`](Episode) with the given id.
+    pub fn get_episode(&self, id: u32) -> Result<episode::Episode, DeezerError> {
+        self.fetch(&episode::get_episode_path(id), episode::Episode::new)
+    }
+
+    /// Returns the [`Comment`](Comment) with the given id.
+    pub fn get_comment(&self, id: u32) -> Result<comment::Comment, DeezerError> {
+        self.fetch(&comment::get_comment_path(id), comment::Comment::new)
     }
 
     /// Returns the [`User`](User) with the given id.
-    pub fn get_user(&self, id: u32) -> user::User {
-        let json = self.client.get(&user::get_user_api(id))
-            .send()
-            .unwrap()
-            .text()
-            .unwrap();
+    pub fn get_user(&self, id: u32) -> Result<user::User, DeezerError> {
+        self.fetch(&user::get_user_path(id), user::User::new)
+    }
+
+    /// Returns the favorite albums of the [`User`](User) with the given id.
+    pub fn get_user_albums(&self, id: u32) -> page::Paginated<'_, T, user::UserAlbum> {
+        self.paginate(&user::get_user_relation_path(id, "albums"))
+    }
+
+    /// Returns the favorite artists of the [`User`](User) with the given id.
+    pub fn get_user_artists(&self, id: u32) -> page::Paginated<'_, T, user::UserArtist> {
+        self.paginate(&user::get_user_relation_path(id, "artists"))
+    }
+
+    /// Returns the favorite tracks of the [`User`](User) with the given id.
+    pub fn get_user_tracks(&self, id: u32) -> page::Paginated<'_, T, user::UserTrack> {
+        self.paginate(&user::get_user_relation_path(id, "tracks"))
+    }
+
+    /// Returns the playlists of the [`User`](User) with the given id.
+    pub fn get_user_playlists(&self, id: u32) -> page::Paginated<'_, T, user::UserPlaylist> {
+        self.paginate(&user::get_user_relation_path(id, "playlists"))
+    }
+
+    /// Returns the users followed by the [`User`](User) with the given id.
+    pub fn get_user_followings(&self, id: u32) -> page::Paginated<'_, T, comment::CommentAuthor> {
+        self.paginate(&user::get_user_relation_path(id, "followings"))
+    }
+
+    /// Returns the followers of the [`User`](User) with the given id.
+    pub fn get_user_followers(&self, id: u32) -> page::Paginated<'_, T, comment::CommentAuthor> {
+        self.paginate(&user::get_user_relation_path(id, "followers"))
+    }
+
+    /// Returns the top tracks of the [`User`](User) with the given id.
+    pub fn get_user_chart_tracks(&self, id: u32) -> page::Paginated<'_, T, user::UserTrack> {
+        self.paginate(&user::get_user_relation_path(id, "charts/tracks"))
+    }
+
+    /// Returns the top albums of the [`User`](User) with the given id.
+    pub fn get_user_chart_albums(&self, id: u32) -> page::Paginated<'_, T, user::UserAlbum> {
+        self.paginate(&user::get_user_relation_path(id, "charts/albums"))
+    }
+
+    /// Returns the top artists of the [`User`](User) with the given id.
+    pub fn get_user_chart_artists(&self, id: u32) -> page::Paginated<'_, T, user::UserArtist> {
+        self.paginate(&user::get_user_relation_path(id, "charts/artists"))
+    }
+
+    /// Returns the top playlists of the [`User`](User) with the given id.
+    pub fn get_user_chart_playlists(&self, id: u32) -> page::Paginated<'_, T, user::UserPlaylist> {
+        self.paginate(&user::get_user_relation_path(id, "charts/playlists"))
+    }
+
+    /// Returns the tracks of the flow of the [`User`](User) with the given id.
+    pub fn get_user_flow(&self, id: u32) -> page::Paginated<'_, T, user::UserTrack> {
+        self.paginate(&user::get_user_relation_path(id, "flow"))
+    }
+
+    /// Returns the favorite radios of the [`User`](User) with the given id.
+    pub fn get_user_radios(&self, id: u32) -> page::Paginated<'_, T, search::SearchRadio> {
+        self.paginate(&user::get_user_relation_path(id, "radios"))
+    }
+
+    /// Returns the [`User`](User) the access token belongs to.
+    pub fn get_me(&self) -> Result<user::User, DeezerError> {
+        self.fetch(&user::get_me_path(), user::User::new)
+    }
+
+    /// Returns the favorite albums of the current user.
+    pub fn get_me_albums(&self) -> page::Paginated<'_, T, user::UserAlbum> {
+        self.paginate(&user::get_me_relation_path("albums"))
+    }
+
+    /// Returns the favorite artists of the current user.
+    pub fn get_me_artists(&self) -> page::Paginated<'_, T, user::UserArtist> {
+        self.paginate(&user::get_me_relation_path("artists"))
+    }
+
+    /// Returns the favorite tracks of the current user.
+    pub fn get_me_tracks(&self) -> page::Paginated<'_, T, user::UserTrack> {
+        self.paginate(&user::get_me_relation_path("tracks"))
+    }
+
+    /// Returns the playlists of the current user.
+    pub fn get_me_playlists(&self) -> page::Paginated<'_, T, user::UserPlaylist> {
+        self.paginate(&user::get_me_relation_path("playlists"))
+    }
+
+    /// Returns the users followed by the current user.
+    pub fn get_me_followings(&self) -> page::Paginated<'_, T, comment::CommentAuthor> {
+        self.paginate(&user::get_me_relation_path("followings"))
+    }
+
+    /// Returns the followers of the current user.
+    pub fn get_me_followers(&self) -> page::Paginated<'_, T, comment::CommentAuthor> {
+        self.paginate(&user::get_me_relation_path("followers"))
+    }
 
-        user::User::new(&json)
+    /// Returns the top tracks of the current user.
+    pub fn get_me_chart_tracks(&self) -> page::Paginated<'_, T, user::UserTrack> {
+        self.paginate(&user::get_me_relation_path("charts/tracks"))
+    }
+
+    /// Returns the top albums of the current user.
+    pub fn get_me_chart_albums(&self) -> page::Paginated<'_, T, user::UserAlbum> {
+        self.paginate(&user::get_me_relation_path("charts/albums"))
+    }
+
+    /// Returns the top artists of the current user.
+    pub fn get_me_chart_artists(&self) -> page::Paginated<'_, T, user::UserArtist> {
+        self.paginate(&user::get_me_relation_path("charts/artists"))
+    }
+
+    /// Returns the top playlists of the current user.
+    pub fn get_me_chart_playlists(&self) -> page::Paginated<'_, T, user::UserPlaylist> {
+        self.paginate(&user::get_me_relation_path("charts/playlists"))
+    }
+
+    /// Returns the tracks of the flow of the current user.
+    pub fn get_me_flow(&self) -> page::Paginated<'_, T, user::UserTrack> {
+        self.paginate(&user::get_me_relation_path("flow"))
+    }
+
+    /// Returns the favorite radios of the current user.
+    pub fn get_me_radios(&self) -> page::Paginated<'_, T, search::SearchRadio> {
+        self.paginate(&user::get_me_relation_path("radios"))
+    }
+
+    /// Adds the [`Artist`](Artist) with the given id to the favorites of the current user.
+    ///
+    /// Succeeds if it already was one.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate deezer_metadata;
+    /// # use std::cell::RefCell;
+    /// # use deezer_metadata::api::Api;
+    /// # use deezer_metadata::api::errors::DeezerError;
+    /// # use deezer_metadata::api::transport::{Request, Response, Transport};
+    /// # // Answers each request with the next body, whatever its url
+    /// # struct Replies(RefCell<Vec<&'static str>>);
+    /// # impl Transport for Replies {
+    /// #     fn send(&self, _: &Request) -> Result<Response, DeezerError> {
+    /// #         Ok(Response::new(200, self.0.borrow_mut().remove(0)))
+    /// #     }
+    /// # }
+    /// # fn main() {
+    /// # let transport = Replies(RefCell::new(vec![
+    /// #     "true",
+    /// #     r#"{"error":{"type":"DataException","message":"already exists","code":801}}"#,
+    /// #     r#"{"data":[{"id":27,"name":"Daft Punk"}],"total":1}"#,
+    /// #     r#"{"error":{"type":"OAuthException","message":"Permission denied","code":200}}"#,
+    /// # ]));
+    /// let deezer = Api::with_transport(transport).with_access_token("t0k3n");
+    ///
+    /// // The second time, Deezer refuses the add and the artist is found in the favorites
+    /// deezer.add_favorite_artist(27).unwrap();
+    /// deezer.add_favorite_artist(27).unwrap();
+    ///
+    /// // Without the `manage_library` permission
+    /// match deezer.add_favorite_artist(27) {
+    ///     Err(DeezerError::PermissionDenied(e)) => assert_eq!(e.code, 200),
+    ///     other => panic!("expected PermissionDenied, got {:?}", other),
+    /// }
+    /// # }
+    /// ```
+    pub fn add_favorite_artist(&self, id: u32) -> Result<(), DeezerError> {
+        self.add_favorite("artists", "artist_id", id.into())
+    }
+
+    /// Removes the [`Artist`](Artist) with the given id from the favorites of the current user.
+    ///
+    /// Succeeds if it wasn't one, but fails with [`DeezerError::NotFound`](DeezerError::NotFound)
+    /// if there's no such artist.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate deezer_metadata;
+    /// # use deezer_metadata::api::Api;
+    /// # use deezer_metadata::api::errors::DeezerError;
+    /// # use deezer_metadata::api::transport::{Method, MemoryTransport, Response};
+    /// # fn main() {
+    /// let transport = MemoryTransport::new()
+    ///     .with_response(Method::Delete, "https://api.deezer.com/user/me/artists?artist_id=27&access_token=t0k3n",
+    ///         Response::new(200, r#"{"error":{"type":"DataException","message":"no data","code":800}}"#))
+    ///     .with_body("https://api.deezer.com/artist/27?access_token=t0k3n", r#"{"id":27,"name":"Daft Punk"}"#)
+    ///     .with_response(Method::Delete, "https://api.deezer.com/user/me/artists?artist_id=0&access_token=t0k3n",
+    ///         Response::new(200, r#"{"error":{"type":"DataException","message":"no data","code":800}}"#))
+    ///     .with_body("https://api.deezer.com/artist/0?access_token=t0k3n",
+    ///         r#"{"error":{"type":"DataException","message":"no data","code":800}}"#)
+    ///     .with_response(Method::Delete, "https://api.deezer.com/user/me/artists?artist_id=27&access_token=r34d",
+    ///         Response::new(200, r#"{"error":{"type":"OAuthException","message":"Permission denied","code":200}}"#));
+    ///
+    /// // Deezer answers the artist isn't a favorite
+    /// let deezer = Api::with_transport(&transport).with_access_token("t0k3n");
+    /// deezer.remove_favorite_artist(27).unwrap();
+    ///
+    /// // There's no artist with this id at all
+    /// match deezer.remove_favorite_artist(0) {
+    ///     Err(DeezerError::NotFound(_)) => {},
+    ///     other => panic!("expected NotFound, got {:?}", other),
+    /// }
+    ///
+    /// // Without the `manage_library` permission
+    /// let deezer = Api::with_transport(&transport).with_access_token("r34d");
+    /// match deezer.remove_favorite_artist(27) {
+    ///     Err(DeezerError::PermissionDenied(e)) => assert_eq!(e.code, 200),
+    ///     other => panic!("expected PermissionDenied, got {:?}", other),
+    /// }
+    /// # }
+    /// ```
+    pub fn remove_favorite_artist(&self, id: u32) -> Result<(), DeezerError> {
+        self.remove_favorite("artists", "artist_id", id.into())
+    }
+
+    /// Adds the [`Album`](Album) with the given id to the favorites of the current user.
+    ///
+    /// Succeeds if it already was one.
+    pub fn add_favorite_album(&self, id: u32) -> Result<(), DeezerError> {
+        self.add_favorite("albums", "album_id", id.into())
+    }
+
+    /// Removes the [`Album`](Album) with the given id from the favorites of the current user.
+    ///
+    /// Succeeds if it wasn't one, but fails with [`DeezerError::NotFound`](DeezerError::NotFound)
+    /// if there's no such album.
+    pub fn remove_favorite_album(&self, id: u32) -> Result<(), DeezerError> {
+        self.remove_favorite("albums", "album_id", id.into())
+    }
+
+    /// Adds the [`Track`](Track) with the given id to the favorites of the current user.
+    ///
+    /// Succeeds if it already was one.
+    pub fn add_favorite_track(&self, id: u32) -> Result<(), DeezerError> {
+        self.add_favorite("tracks", "track_id", id.into())
+    }
+
+    /// Removes the [`Track`](Track) with the given id from the favorites of the current user.
+    ///
+    /// Succeeds if it wasn't one, but fails with [`DeezerError::NotFound`](DeezerError::NotFound)
+    /// if there's no such track.
+    pub fn remove_favorite_track(&self, id: u32) -> Result<(), DeezerError> {
+        self.remove_favorite("tracks", "track_id", id.into())
+    }
+
+    /// Adds the [`Playlist`](Playlist) with the given id to the favorites of the current user.
+    ///
+    /// Succeeds if it already was one.
+    pub fn add_favorite_playlist(&self, id: u64) -> Result<(), DeezerError> {
+        self.add_favorite("playlists", "playlist_id", id)
+    }
+
+    /// Removes the [`Playlist`](Playlist) with the given id from the favorites of the current user.
+    ///
+    /// Succeeds if it wasn't one, but fails with [`DeezerError::NotFound`](DeezerError::NotFound)
+    /// if there's no such playlist.
+    pub fn remove_favorite_playlist(&self, id: u64) -> Result<(), DeezerError> {
+        self.remove_favorite("playlists", "playlist_id", id)
+    }
+
+    /// Adds the [`Radio`](Radio) with the given id to the favorites of the current user.
+    ///
+    /// Succeeds if it already was one.
+    pub fn add_favorite_radio(&self, id: u32) -> Result<(), DeezerError> {
+        self.add_favorite("radios", "radio_id", id.into())
+    }
+
+    /// Removes the [`Radio`](Radio) with the given id from the favorites of the current user.
+    ///
+    /// Succeeds if it wasn't one, but fails with [`DeezerError::NotFound`](DeezerError::NotFound)
+    /// if there's no such radio.
+    pub fn remove_favorite_radio(&self, id: u32) -> Result<(), DeezerError> {
+        self.remove_favorite("radios", "radio_id", id.into())
+    }
+
+    /// Makes the current user follow the [`User`](User) with the given id.
+    ///
+    /// Succeeds if they already were followed.
+    pub fn follow_user(&self, id: u32) -> Result<(), DeezerError> {
+        self.add_favorite("followings", "user_id", id.into())
+    }
+
+    /// Makes the current user stop following the [`User`](User) with the given id.
+    ///
+    /// Succeeds if they weren't followed, but fails with [`DeezerError::NotFound`](DeezerError::NotFound)
+    /// if there's no such user.
+    pub fn unfollow_user(&self, id: u32) -> Result<(), DeezerError> {
+        self.remove_favorite("followings", "user_id", id.into())
     }
 
     /// Returns the [`Playlist`](Playlist) with the given id.
-    pub fn get_playlist(&self, id: u32) -> playlist::Playlist {
-        let json = self.client.get(&playlist::get_playlist_api(id))
-            .send()
-            .unwrap()
-            .text()
-            .unwrap();
+    pub fn get_playlist(&self, id: u64) -> Result<playlist::Playlist, DeezerError> {
+        self.fetch(&playlist::get_playlist_path(id), playlist::Playlist::new)
+    }
 
-        playlist::Playlist::new(&json)
+    /// Returns the comments posted on the [`Playlist`](Playlist) with the given id.
+    pub fn get_playlist_comments(&self, id: u64) -> page::Paginated<'_, T, comment::Comment> {
+        self.paginate(&playlist::get_playlist_relation_path(id, "comments"))
+    }
+
+    /// Returns every track of the [`Playlist`](Playlist) with the given id.
+    ///
+    /// Unlike [`Playlist::tracks`](Playlist), walks every page of the tracklist,
+    /// so no track is left out of large playlists.
+    pub fn get_playlist_tracks(&self, id: u64) -> Result<Vec<playlist::PlaylistTrack>, DeezerError> {
+        self.paginate(&playlist::get_playlist_relation_path(id, "tracks")).collect()
+    }
+
+    /// Creates a playlist owned by the current user and returns its id.
+    pub fn create_playlist(&self, title: &str) -> Result<u64, DeezerError> {
+        let playlists = user::get_me_relation_path("playlists");
+        let json = self.write(Method::Post, &playlists, &[
+            ("title", title.to_owned()),
+        ], &[&playlists])?;
+
+        objects::from_json::<playlist::CreatedPlaylist>(&json).map(|created| created.id)
+    }
+
+    /// Applies the given changes to the [`Playlist`](Playlist) with the given id.
+    pub fn update_playlist(&self, id: u64, update: &playlist::PlaylistUpdate) -> Result<(), DeezerError> {
+        let path = playlist::get_playlist_path(id);
+        let json = self.write(Method::Post, &path, &update.to_params(), &[
+            &path,
+            &user::get_me_relation_path("playlists"),
+        ])?;
+
+        objects::from_json_ack(&json)
+    }
+
+    /// Deletes the [`Playlist`](Playlist) with the given id.
+    pub fn delete_playlist(&self, id: u64) -> Result<(), DeezerError> {
+        let path = playlist::get_playlist_path(id);
+        let json = self.write(Method::Delete, &path, &[], &[
+            &path,
+            &user::get_me_relation_path("playlists"),
+        ])?;
+
+        objects::from_json_ack(&json)
+    }
+
+    /// Adds the tracks with the given ids at the end of the [`Playlist`](Playlist) with the given id.
+    pub fn add_playlist_tracks(&self, id: u64, tracks: &[u32]) -> Result<(), DeezerError> {
+        // The playlist and the lists of playlists embed its tracks, their number and checksum
+        let json = self.write(Method::Post, &playlist::get_playlist_relation_path(id, "tracks"), &[
+            ("songs", playlist::join_ids(tracks)),
+        ], &[&playlist::get_playlist_path(id), &user::get_me_relation_path("playlists")])?;
+
+        objects::from_json_ack(&json)
+    }
+
+    /// Removes the tracks with the given ids from the [`Playlist`](Playlist) with the given id.
+    pub fn remove_playlist_tracks(&self, id: u64, tracks: &[u32]) -> Result<(), DeezerError> {
+        let json = self.write(Method::Delete, &playlist::get_playlist_relation_path(id, "tracks"), &[
+            ("songs", playlist::join_ids(tracks)),
+        ], &[&playlist::get_playlist_path(id), &user::get_me_relation_path("playlists")])?;
+
+        objects::from_json_ack(&json)
+    }
+
+    /// Reorders the tracks of the [`Playlist`](Playlist) with the given id,
+    /// `order` listing every track id in its new position.
+    pub fn reorder_playlist_tracks(&self, id: u64, order: &[u32]) -> Result<(), DeezerError> {
+        let json = self.write(Method::Post, &playlist::get_playlist_relation_path(id, "tracks"), &[
+            ("order", playlist::join_ids(order)),
+        ], &[&playlist::get_playlist_path(id), &user::get_me_relation_path("playlists")])?;
+
+        objects::from_json_ack(&json)
     }
 
     /// Returns the [`Editorial`](Editorial) with the given id.
-    pub fn get_editorial(&self, id: u32) -> editorial::Editorial {
-        let json = self.client.get(&editorial::get_editorial_api(id))
-            .send()
-            .unwrap()
-            .text()
-            .unwrap();
+    pub fn get_editorial(&self, id: u32) -> Result<editorial::Editorial, DeezerError> {
+        self.fetch(&editorial::get_editorial_path(id), editorial::Editorial::new)
+    }
 
-        editorial::Editorial::new(&json)
+    /// Returns every [`Editorial`](Editorial) available on Deezer.
+    pub fn get_editorials(&self) -> Result<Vec<editorial::Editorial>, DeezerError> {
+        self.paginate(&editorial::get_editorials_path()).collect()
+    }
+
+    /// Returns the albums selected by the [`Editorial`](Editorial) with the given id.
+    pub fn get_editorial_selection(&self, id: u32) -> page::Paginated<'_, T, editorial::EditorialAlbum> {
+        self.paginate(&editorial::get_editorial_relation_path(id, "selection"))
+    }
+
+    /// Returns the [`Chart`](Chart) of the [`Editorial`](Editorial) with the given id.
+    pub fn get_editorial_charts(&self, id: u32) -> Result<chart::Chart, DeezerError> {
+        self.fetch(&editorial::get_editorial_relation_path(id, "charts"), chart::Chart::new)
+    }
+
+    /// Returns the [`Chart`](Chart) for the genre with the given id (`0` for every genre).
+    pub fn get_chart_for(&self, genre_id: u32) -> Result<chart::Chart, DeezerError> {
+        self.fetch(&chart::get_chart_for_path(genre_id), chart::Chart::new)
+    }
+
+    /// Returns the top tracks for the genre with the given id.
+    ///
+    /// Use [`.limit()`](page::Paginated) to get more than the top 10 in one request.
+    pub fn get_chart_tracks(&self, genre_id: u32) -> page::Paginated<'_, T, chart::ChartTrack> {
+        self.paginate(&chart::get_chart_section_path(genre_id, "tracks"))
+    }
+
+    /// Returns the top albums for the genre with the given id.
+    ///
+    /// Use [`.limit()`](page::Paginated) to get more than the top 10 in one request.
+    pub fn get_chart_albums(&self, genre_id: u32) -> page::Paginated<'_, T, chart::ChartAlbum> {
+        self.paginate(&chart::get_chart_section_path(genre_id, "albums"))
+    }
+
+    /// Returns the top artists for the genre with the given id.
+    ///
+    /// Use [`.limit()`](page::Paginated) to get more than the top 10 in one request.
+    pub fn get_chart_artists(&self, genre_id: u32) -> page::Paginated<'_, T, chart::ChartArtist> {
+        self.paginate(&chart::get_chart_section_path(genre_id, "artists"))
+    }
+
+    /// Returns the top playlists for the genre with the given id.
+    ///
+    /// Use [`.limit()`](page::Paginated) to get more than the top 10 in one request.
+    pub fn get_chart_playlists(&self, genre_id: u32) -> page::Paginated<'_, T, chart::ChartPlaylist> {
+        self.paginate(&chart::get_chart_section_path(genre_id, "playlists"))
+    }
+
+    /// Returns the top podcasts for the genre with the given id.
+    ///
+    /// Use [`.limit()`](page::Paginated) to get more than the top 10 in one request.
+    pub fn get_chart_podcasts(&self, genre_id: u32) -> page::Paginated<'_, T, chart::GenrePodcast> {
+        self.paginate(&chart::get_chart_section_path(genre_id, "podcasts"))
+    }
+
+    /// Returns the new releases of the [`Editorial`](Editorial) with the given id.
+    pub fn get_editorial_releases(&self, id: u32) -> page::Paginated<'_, T, editorial::EditorialAlbum> {
+        self.paginate(&editorial::get_editorial_relation_path(id, "releases"))
     }
 
     /// Returns the [`Radio`](Radio) with the given id.
-    pub fn get_radio(&self, id: u32) -> radio::Radio {
-        let json = self.client.get(&radio::get_radio_api(id))
-            .send()
-            .unwrap()
-            .text()
-            .unwrap();
+    pub fn get_radio(&self, id: u32) -> Result<radio::Radio, DeezerError> {
+        self.fetch(&radio::get_radio_path(id), radio::Radio::new)
+    }
 
-        radio::Radio::new(&json)
+    /// Returns every radio available on Deezer.
+    pub fn get_radios(&self) -> Result<Vec<radio::SearchRadio>, DeezerError> {
+        self.paginate(&radio::get_radios_path()).collect()
     }
 
-    /// Returns the [`Info`](Info) for the current country.
-    pub fn get_info(&self) -> info::Info {
-        let json = self.client.get(&info::get_info_api())
-            .send()
-            .unwrap()
-            .text()
-            .unwrap();
+    /// Returns the radios available on Deezer, grouped by genre.
+    pub fn get_radio_genres(&self) -> Result<Vec<radio::RadioGenre>, DeezerError> {
+        self.paginate(&radio::get_radio_list_path("genres")).collect()
+    }
+
+    /// Returns the most popular radios.
+    pub fn get_radio_top(&self) -> page::Paginated<'_, T, radio::SearchRadio> {
+        self.paginate(&radio::get_radio_list_path("top"))
+    }
+
+    /// Returns the radios split by list.
+    pub fn get_radio_lists(&self) -> page::Paginated<'_, T, radio::SearchRadio> {
+        self.paginate(&radio::get_radio_list_path("lists"))
+    }
 
-        info::Info::new(&json)
+    /// Returns the tracks of the [`Radio`](Radio) with the given id.
+    pub fn get_radio_tracks(&self, id: u32) -> page::Paginated<'_, T, artist::ArtistTrack> {
+        self.paginate(&radio::get_radio_tracks_path(id))
     }
 
-    /// Returns the [`Chart`](Chart) for the given id.
-    pub fn get_chart(&self) -> chart::Chart {
-        let json = self.client.get(&chart::get_chart_api())
-            .send()
-            .unwrap()
-            .text()
-            .unwrap();
+    /// Returns the [`Info`](Info) for the current country.
+    pub fn get_info(&self) -> Result<info::Info, DeezerError> {
+        self.fetch(&info::get_info_path(), info::Info::new)
+    }
 
-        chart::Chart::new(&json)
+    /// Returns the [`Chart`](Chart) across every genre.
+    pub fn get_chart(&self) -> Result<chart::Chart, DeezerError> {
+        self.fetch(&chart::get_chart_path(), chart::Chart::new)
     }
 
     /// Returns the [`Options`](Options) for the current user.
-    pub fn get_options(&self) -> options::Options {
-        let json = self.client.get(&options::get_options_api())
-            .send()
-            .unwrap()
-            .text()
-            .unwrap();
+    pub fn get_options(&self) -> Result<options::Options, DeezerError> {
+        self.fetch(&options::get_options_path(), options::Options::new)
+    }
+
+    /// Returns the url to send a user to so they grant the application the given permissions.
+    ///
+    /// Deezer then redirects them to `redirect_uri` with a `code` to pass to
+    /// [`exchange_code`](#method.exchange_code).
+    pub fn authorize_url(&self, app_id: u32, redirect_uri: &str, perms: &[oauth::Permission]) -> String {
+        oauth::get_authorize_url(&self.connect_url, app_id, redirect_uri, perms)
+    }
+
+    /// Exchanges the code Deezer handed to the redirect uri for an [`AccessToken`](oauth::AccessToken).
+    pub fn exchange_code(&self, app_id: u32, secret: &str, code: &str) -> Result<oauth::AccessToken, DeezerError> {
+        let url = oauth::get_access_token_url(&self.connect_url, app_id, secret, code);
+        self.check_online(&self.connect_url)?;
+
+        let resp = self.transport.send(&Request {
+            method: Method::Get,
+            url,
+        })?;
+
+        if !(200..300).contains(&resp.status) {
+            return Err(DeezerError::HttpStatus(resp.status));
+        }
+
+        oauth::AccessToken::new(&resp.body)
+    }
+
+    /// Returns the first page of tracks matching the given query.
+    ///
+    /// Accepts a plain `&str` or a [`SearchQuery`](search::SearchQuery).
+    pub fn search_tracks<Q: Into<search::SearchQuery>>(&self, query: Q)
+        -> Result<page::Page<search::SearchTrack>, DeezerError>
+    {
+        self.fetch(&search::get_search_path("track", &query.into()), page::Page::new)
+    }
+
+    /// Returns the first page of albums matching the given query.
+    ///
+    /// Accepts a plain `&str` or a [`SearchQuery`](search::SearchQuery).
+    pub fn search_albums<Q: Into<search::SearchQuery>>(&self, query: Q)
+        -> Result<page::Page<search::SearchAlbum>, DeezerError>
+    {
+        self.fetch(&search::get_search_path("album", &query.into()), page::Page::new)
+    }
+
+    /// Returns the first page of artists matching the given query.
+    ///
+    /// Accepts a plain `&str` or a [`SearchQuery`](search::SearchQuery).
+    pub fn search_artists<Q: Into<search::SearchQuery>>(&self, query: Q)
+        -> Result<page::Page<search::SearchArtist>, DeezerError>
+    {
+        self.fetch(&search::get_search_path("artist", &query.into()), page::Page::new)
+    }
 
-        options::Options::new(&json)
+    /// Returns the first page of playlists matching the given query.
+    ///
+    /// Accepts a plain `&str` or a [`SearchQuery`](search::SearchQuery).
+    pub fn search_playlists<Q: Into<search::SearchQuery>>(&self, query: Q)
+        -> Result<page::Page<search::ChartPlaylist>, DeezerError>
+    {
+        self.fetch(&search::get_search_path("playlist", &query.into()), page::Page::new)
     }
-}
\ No newline at end of file
+
+    /// Returns the first page of users matching the given query.
+    ///
+    /// Accepts a plain `&str` or a [`SearchQuery`](search::SearchQuery).
+    pub fn search_users<Q: Into<search::SearchQuery>>(&self, query: Q)
+        -> Result<page::Page<search::CommentAuthor>, DeezerError>
+    {
+        self.fetch(&search::get_search_path("user", &query.into()), page::Page::new)
+    }
+
+    /// Returns the first page of radios matching the given query.
+    ///
+    /// Accepts a plain `&str` or a [`SearchQuery`](search::SearchQuery).
+    pub fn search_radios<Q: Into<search::SearchQuery>>(&self, query: Q)
+        -> Result<page::Page<search::SearchRadio>, DeezerError>
+    {
+        self.fetch(&search::get_search_path("radio", &query.into()), page::Page::new)
+    }
+
+    /// Parses a Deezer link into a [`DeezerRef`](DeezerRef).
+    ///
+    /// Short links (`deezer.page.link`, `link.deezer.com`) are followed first,
+    /// any other link is parsed without sending a request.
+    pub fn parse_link(&self, url: &str) -> Result<link::DeezerRef, DeezerError> {
+
+        if !link::is_short_link(url) {
+            return url.parse();
+        }
+
+        self.check_online(url)?;
+
+        let response = self.transport.send(&Request {
+            method: Method::Get,
+            url: url.trim().to_owned(),
+        })?;
+
+        if !(200..300).contains(&response.status) {
+            return Err(DeezerError::HttpStatus(response.status));
+        }
+
+        match response.url {
+            Some(ref location) if !link::is_short_link(location) => location.parse(),
+            _ => Err(DeezerError::InvalidLink(url.to_owned())),
+        }
+    }
+
+    /// Returns the object the given [`DeezerRef`](DeezerRef) points to.
+    pub fn resolve(&self, reference: &link::DeezerRef) -> Result<link::DeezerObject, DeezerError> {
+
+        use self::link::{DeezerObject, DeezerRef};
+
+        Ok(match *reference {
+            DeezerRef::Track(id) => DeezerObject::Track(self.get_track(id)?),
+            DeezerRef::Album(id) => DeezerObject::Album(self.get_album(id)?),
+            DeezerRef::Artist(id) => DeezerObject::Artist(self.get_artist(id)?),
+            DeezerRef::Playlist(id) => DeezerObject::Playlist(self.get_playlist(id)?),
+            DeezerRef::User(id) => DeezerObject::User(self.get_user(id)?),
+            DeezerRef::Radio(id) => DeezerObject::Radio(self.get_radio(id)?),
+            DeezerRef::Podcast(id) => DeezerObject::Podcast(self.get_podcast(id)?),
+            DeezerRef::Episode(id) => DeezerObject::Episode(self.get_episode(id)?),
+        })
+    }
+}
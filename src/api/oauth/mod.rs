@@ -0,0 +1,160 @@
+//! Contains the [`AccessToken`](AccessToken) struct returned by Deezer's OAuth flow.
+//! Also contains the [`Permission`](Permission) enum.
+#![deny(warnings, missing_docs)]
+
+use url::form_urlencoded;
+
+use api::errors::{
+    ApiError,
+    DeezerError,
+};
+use api::objects::from_json;
+
+/// The url of Deezer's OAuth host.
+pub const DEFAULT_CONNECT_URL: &str = "https://connect.deezer.com";
+
+/// An access token, obtained by exchanging the code Deezer hands to the redirect uri.
+///
+/// # Examples
+///
+/// Send the user to [`Api::authorize_url`], then exchange the code Deezer
+/// redirects them back with:
+///
+/// ```rust
+/// # extern crate deezer_metadata;
+/// # use deezer_metadata::api::Api;
+/// # use deezer_metadata::api::oauth::Permission;
+/// # use deezer_metadata::api::transport::MemoryTransport;
+/// # fn main() {
+/// # let transport = MemoryTransport::new()
+/// #     .with_body(
+/// #         "http://localhost:8081/oauth/access_token.php?app_id=123456&secret=s3cr3t&code=fr4c0d3&output=json",
+/// #         r#"{"access_token": "t0k3n", "expires": 3600}"#,
+/// #     )
+/// #     .with_body("http://localhost:8080/user/me?access_token=t0k3n", r#"{
+/// #         "id": 5, "name": "me", "email": "me@example.com", "link": "", "picture": "",
+/// #         "picture_small": "", "picture_medium": "", "picture_big": "", "picture_xl": "",
+/// #         "country": "FR", "tracklist": ""
+/// #     }"#);
+/// let deezer = Api::builder()
+///     .base_url("http://localhost:8080")
+///     .connect_url("http://localhost:8081")
+/// #   .transport(transport)
+///     .build();
+///
+/// let url = deezer.authorize_url(123456, "https://example.com/callback", &[
+///     Permission::BasicAccess,
+///     Permission::Email,
+/// ]);
+/// assert_eq!(url, "http://localhost:8081/oauth/auth.php?app_id=123456\
+///     &redirect_uri=https%3A%2F%2Fexample.com%2Fcallback&perms=basic_access%2Cemail");
+///
+/// // Once the user is redirected to `https://example.com/callback?code=fr4c0d3`
+/// let token = deezer.exchange_code(123456, "s3cr3t", "fr4c0d3").unwrap();
+///
+/// // Every request is now made on behalf of the user
+/// let deezer = deezer.with_access_token(&token.access_token);
+/// let me = deezer.get_me().unwrap();
+/// # assert_eq!(token.expires_in_seconds, 3600);
+/// # assert_eq!(me.email, "me@example.com");
+/// # }
+/// ```
+///
+/// [`Api::authorize_url`]: ../struct.Api.html#method.authorize_url
+#[derive(Deserialize, Serialize, Debug, Clone, PartialEq)]
+pub struct AccessToken {
+
+    /// The token to pass to [`Api::with_access_token`](../struct.Api.html#method.with_access_token)
+    pub access_token: String,
+
+    /// The number of seconds the token is valid for, `0` if it never expires (`offline_access`)
+    #[serde(default)]
+    #[serde(rename = "expires")]
+    pub expires_in_seconds: u64,
+}
+
+impl AccessToken {
+
+    /// Deezer answers a bad code with a plain text message (e.g. `wrong code`).
+    pub(crate) fn new(body: &str) -> Result<Self, DeezerError> {
+
+        if serde_json::from_str::<serde_json::Value>(body).is_ok() {
+            return from_json(body);
+        }
+
+        Err(ApiError {
+            error_type: "OAuthException".to_owned(),
+            message: body.trim().to_owned(),
+            code: 0,
+        }.into())
+    }
+}
+
+/// A permission an application asks the user for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Permission {
+
+    /// `basic_access`: read the user's basic information
+    BasicAccess,
+
+    /// `email`: read the user's email
+    Email,
+
+    /// `offline_access`: get a token that never expires
+    OfflineAccess,
+
+    /// `manage_library`: manage the user's playlists and favorites
+    ManageLibrary,
+
+    /// `manage_community`: manage the user's followings
+    ManageCommunity,
+
+    /// `delete_library`: delete the user's playlists and favorites
+    DeleteLibrary,
+
+    /// `listening_history`: read the user's listening history
+    ListeningHistory,
+}
+
+impl Permission {
+
+    /// Returns the value Deezer expects in the `perms` parameter.
+    pub fn as_str(&self) -> &'static str {
+        match *self {
+            Permission::BasicAccess => "basic_access",
+            Permission::Email => "email",
+            Permission::OfflineAccess => "offline_access",
+            Permission::ManageLibrary => "manage_library",
+            Permission::ManageCommunity => "manage_community",
+            Permission::DeleteLibrary => "delete_library",
+            Permission::ListeningHistory => "listening_history",
+        }
+    }
+}
+
+/// Takes an app id, a redirect uri and permissions and produces the url to send the user to.
+pub(crate) fn get_authorize_url(connect_url: &str, app_id: u32, redirect_uri: &str, perms: &[Permission]) -> String {
+
+    let perms = perms.iter().map(Permission::as_str).collect::<Vec<_>>().join(",");
+
+    let query = form_urlencoded::Serializer::new(String::new())
+        .append_pair("app_id", &app_id.to_string())
+        .append_pair("redirect_uri", redirect_uri)
+        .append_pair("perms", &perms)
+        .finish();
+
+    format!("{}/oauth/auth.php?{}", connect_url, query)
+}
+
+/// Takes an app id, its secret and a code and produces the url exchanging the code for a token.
+pub(crate) fn get_access_token_url(connect_url: &str, app_id: u32, secret: &str, code: &str) -> String {
+
+    let query = form_urlencoded::Serializer::new(String::new())
+        .append_pair("app_id", &app_id.to_string())
+        .append_pair("secret", secret)
+        .append_pair("code", code)
+        .append_pair("output", "json")
+        .finish();
+
+    format!("{}/oauth/access_token.php?{}", connect_url, query)
+}
@@ -1,9 +1,16 @@
 //! Contains the [`Album`](Album) struct.
 //! Also contains a few other helper structs.
-#[deny(warnings, missing_docs)]
-#[allow(dead_code)]
+#![deny(warnings, missing_docs)]
+#![allow(dead_code)]
+
+use api::Api;
+use api::errors::DeezerError;
+use api::transport::Transport;
+use api::objects::{
+    deserialize_map,
+    from_json,
+};
 
-use api::objects::deserialize_map;
 use api::objects::artist::Artist;
 use api::objects::track::Track;
 use api::objects::genre::Genre;
@@ -19,7 +26,7 @@ use api::objects::genre::Genre;
 /// # use deezer_metadata::api::objects::album::Album;
 /// # fn main() {
 /// // Pass the album id into the 'get' method
-/// let album = Album::get(302127);
+/// let album = Album::get(302127).unwrap();
 /// # assert_eq!(album.id, 302127);
 /// # }
 /// ```
@@ -35,9 +42,9 @@ use api::objects::genre::Genre;
 /// let deezer = Api::new();
 ///
 /// // Get as many albums as you want with the same Api Client
-/// let album1 = deezer.get_album(302127);
-/// let album2 = deezer.get_album(302128);
-/// let album3 = deezer.get_album(302129);
+/// let album1 = deezer.get_album(302127).unwrap();
+/// let album2 = deezer.get_album(302128).unwrap();
+/// let album3 = deezer.get_album(302129).unwrap();
 /// # assert_eq!(album1.id, 302127);
 /// # assert_eq!(album2.id, 302128);
 /// # assert_eq!(album3.id, 302129);
@@ -138,9 +145,9 @@ pub struct Album {
 
 impl Album {
 
-    pub(crate) fn new(json: &str) -> Self {
+    pub(crate) fn new(json: &str) -> Result<Self, DeezerError> {
 
-        let mut album: Self = serde_json::from_str(&json).unwrap();
+        let mut album: Self = from_json(json)?;
 
         // TODO: when 'new' and 'get' are made into a trait impl, add a local method call here so
         // any struct specific checks like what is here below can be done without overriding 'new'
@@ -150,24 +157,25 @@ impl Album {
             album.genre_id = None;
         }
 
-        album
+        Ok(album)
     }
 
     /// Returns an `Album` from a album id.
     ///
-    /// Doesn't use [`Api`](Api), better suited for single uses.
+    /// Creates a new [`Api`](Api) on every call, better suited for single uses.
     ///
     /// If you need to make a lot of requests, use [`Api`](Api).
-    pub fn get(id: u32) -> Self {
-
-        // Get the track api
-        let album_api = get_album_api(id);
-
-        // Get the json for the track
-        let mut resp = reqwest::get(&album_api).unwrap();
-        let json = resp.text().unwrap();
+    pub fn get(id: u32) -> Result<Self, DeezerError> {
+        Api::new().get_album(id)
+    }
 
-        Self::new(&json)
+    /// Returns an `Album` from its UPC (e.g. `724384960650`).
+    ///
+    /// Creates a new [`Api`](Api) on every call, better suited for single uses.
+    ///
+    /// If you need to make a lot of requests, use [`Api`](Api).
+    pub fn get_by_upc(upc: &str) -> Result<Self, DeezerError> {
+        Api::new().get_album_by_upc(upc)
     }
 }
 
@@ -214,10 +222,16 @@ pub struct ContributorArtist {
 
 impl ContributorArtist {
 
-    /// Returns the corresponding [`Artist`](Artist) with all the information available.
-    pub fn get_full(&self) -> Artist {
+    /// Returns the corresponding [`Artist`](Artist) with all the information available,
+    /// through a new uncached [`Api`](Api).
+    pub fn get_full(&self) -> Result<Artist, DeezerError> {
         Artist::get(self.id)
     }
+
+    /// Returns the corresponding [`Artist`](Artist) through the given [`Api`](Api) and its cache.
+    pub fn get_full_with<T: Transport>(&self, api: &Api<T>) -> Result<Artist, DeezerError> {
+        api.get_artist(self.id)
+    }
 }
 
 /// Shortened version of [`Artist`].
@@ -252,10 +266,16 @@ pub struct AlbumArtist {
 
 impl AlbumArtist {
 
-    /// Returns the corresponding [`Artist`](Artist) with all the information available.
-    pub fn get_full(&self) -> Artist {
+    /// Returns the corresponding [`Artist`](Artist) with all the information available,
+    /// through a new uncached [`Api`](Api).
+    pub fn get_full(&self) -> Result<Artist, DeezerError> {
         Artist::get(self.id)
     }
+
+    /// Returns the corresponding [`Artist`](Artist) through the given [`Api`](Api) and its cache.
+    pub fn get_full_with<T: Transport>(&self, api: &Api<T>) -> Result<Artist, DeezerError> {
+        api.get_artist(self.id)
+    }
 }
 
 /// Shortened version of [`Artist`].
@@ -278,10 +298,16 @@ pub struct AlbumTrackArtist {
 
 impl AlbumTrackArtist {
 
-    /// Returns the corresponding [`Artist`](Artist) with all the information available.
-    pub fn get_full(&self) -> Artist {
+    /// Returns the corresponding [`Artist`](Artist) with all the information available,
+    /// through a new uncached [`Api`](Api).
+    pub fn get_full(&self) -> Result<Artist, DeezerError> {
         Artist::get(self.id)
     }
+
+    /// Returns the corresponding [`Artist`](Artist) through the given [`Api`](Api) and its cache.
+    pub fn get_full_with<T: Transport>(&self, api: &Api<T>) -> Result<Artist, DeezerError> {
+        api.get_artist(self.id)
+    }
 }
 
 /// Shortened version of [`Track`].
@@ -330,10 +356,91 @@ pub struct AlbumTrack {
 
 impl AlbumTrack {
 
-    /// Returns the corresponding [`Track`](Track) with all the information available.
-    pub fn get_full(&self) -> Track {
+    /// Returns the corresponding [`Track`](Track) with all the information available,
+    /// through a new uncached [`Api`](Api).
+    pub fn get_full(&self) -> Result<Track, DeezerError> {
         Track::get(self.id)
     }
+
+    /// Returns the corresponding [`Track`](Track) through the given [`Api`](Api) and its cache.
+    pub fn get_full_with<T: Transport>(&self, api: &Api<T>) -> Result<Track, DeezerError> {
+        api.get_track(self.id)
+    }
+}
+
+/// Shortened version of [`Track`], with its position in the album.
+/// Use [`.get_full()`] for the corresponding [`Track`] struct.
+///
+/// Returned when walking the album's whole tracklist.
+///
+/// [`Track`]: Track
+/// [`.get_full()`]: struct.AlbumTracklistTrack.html#method.get_full
+#[derive(Deserialize, Serialize, Debug)]
+pub struct AlbumTracklistTrack {
+
+    /// `The track's Deezer id`
+    pub id: u32,
+
+    /// `True if the track is readable in the player for the current user`
+    pub readable: bool,
+
+    /// `The track's full title`
+    pub title: String,
+
+    /// `The track's short title`
+    pub title_short: String,
+
+    /// `The track version`
+    #[serde(default)]
+    pub title_version: String,
+
+    /// `The track's isrc`
+    #[serde(default)]
+    pub isrc: Option<String>,
+
+    /// `The url of the track on Deezer`
+    pub link: String,
+
+    /// `The track's duration in seconds`
+    #[serde(rename = "duration")]
+    pub duration_in_seconds: u32,
+
+    /// `The position of the track in its album's disk`
+    #[serde(rename = "track_position")]
+    pub track_position_in_album: u32,
+
+    /// `The album's disk number the track is on`
+    #[serde(rename = "disk_number")]
+    pub album_disk_number: u32,
+
+    /// `The track's Deezer rank`
+    pub rank: u32,
+
+    /// `Whether the track contains explicit lyrics`
+    #[serde(rename = "explicit_lyrics")]
+    pub has_explicit_lyrics: bool,
+
+    /// `The url of track's preview file. This file contains the first 30 seconds of the track`
+    #[serde(default)]
+    #[serde(rename = "preview")]
+    pub preview_url: Option<String>,
+
+    /// `AlbumTrackArtist object`
+    pub artist: AlbumTrackArtist,
+}
+
+impl AlbumTracklistTrack {
+
+    /// Returns the corresponding [`Track`](Track) with all the information available,
+    /// through a new uncached [`Api`](Api).
+    pub fn get_full(&self) -> Result<Track, DeezerError> {
+        Track::get(self.id)
+    }
+
+    /// Returns the corresponding [`Track`](Track) through the given [`Api`](Api) and its cache.
+    pub fn get_full_with<T: Transport>(&self, api: &Api<T>) -> Result<Track, DeezerError> {
+        api.get_track(self.id)
+    }
 }
 
 /// Shortened version of [`Genre`].
@@ -356,15 +463,47 @@ pub struct AlbumGenre {
 
 impl AlbumGenre {
 
-    /// Returns the corresponding [`Genre`](Genre) with all the information available.
-    pub fn get_full(&self) -> Genre {
+    /// Returns the corresponding [`Genre`](Genre) with all the information available,
+    /// through a new uncached [`Api`](Api).
+    pub fn get_full(&self) -> Result<Genre, DeezerError> {
         Genre::get(self.id)
     }
+
+    /// Returns the corresponding [`Genre`](Genre) through the given [`Api`](Api) and its cache.
+    pub fn get_full_with<T: Transport>(&self, api: &Api<T>) -> Result<Genre, DeezerError> {
+        api.get_genre(self.id)
+    }
+}
+
+/// Takes an id and produces the appropriate api path.
+pub(crate) fn get_album_path(id: u32) -> String {
+
+    // Construct the api path with the given id
+    "album/".to_owned() + &id.to_string()
+}
+
+/// Takes a UPC and produces the appropriate api path.
+///
+/// Both UPC-A (12 digits) and EAN-13 (13 digits) codes are accepted, anything
+/// else is rejected with [`DeezerError::InvalidCode`].
+pub(crate) fn get_album_by_upc_path(upc: &str) -> Result<String, DeezerError> {
+
+    let code = upc.trim();
+
+    let is_valid = (code.len() == 12 || code.len() == 13)
+        && code.bytes().all(|b| b.is_ascii_digit());
+
+    if !is_valid {
+        return Err(DeezerError::InvalidCode { kind: "UPC", code: upc.to_owned() });
+    }
+
+    // Construct the api path with the given code
+    Ok("album/upc:".to_owned() + code)
 }
 
-/// Takes an id and produces the appropriate api url.
-pub(crate) fn get_album_api(id: u32) -> String {
+/// Takes an id and a relation (`tracks`, `fans`, ...) and produces the appropriate api path.
+pub(crate) fn get_album_relation_path(id: u32, relation: &str) -> String {
 
-    // Construct the api url with the given id
-    "https://api.deezer.com/album/".to_owned() + &id.to_string()
-}
\ No newline at end of file
+    // Construct the api path with the given id
+    format!("album/{}/{}", id, relation)
+}
@@ -1,6 +1,18 @@
 //! Contains the [`Artist`](Artist) struct.
-#[deny(warnings, missing_docs)]
-#[allow(dead_code)]
+//! Also contains a few other helper structs.
+#![deny(warnings, missing_docs)]
+#![allow(dead_code)]
+
+use api::Api;
+use api::errors::DeezerError;
+use api::transport::Transport;
+use api::objects::from_json;
+use api::objects::album::{
+    Album,
+    AlbumTrackArtist,
+};
+use api::objects::playlist::PlaylistTrackAlbum;
+use api::objects::track::Track;
 
 /// Contains all the information provided for an Artist.
 ///
@@ -13,7 +25,7 @@
 /// # use deezer_metadata::api::objects::artist::Artist;
 /// # fn main() {
 /// // Pass the artist id into the 'get' method
-/// let artist = Artist::get(27);
+/// let artist = Artist::get(27).unwrap();
 /// # assert_eq!(artist.id, 27);
 /// # }
 /// ```
@@ -29,9 +41,9 @@
 /// let deezer = Api::new();
 ///
 /// // Get as many artists as you want with the same Api Client
-/// let artist1 = deezer.get_artist(27);
-/// let artist2 = deezer.get_artist(28);
-/// let artist3 = deezer.get_artist(29);
+/// let artist1 = deezer.get_artist(27).unwrap();
+/// let artist2 = deezer.get_artist(28).unwrap();
+/// let artist3 = deezer.get_artist(29).unwrap();
 /// # assert_eq!(artist1.id, 27);
 /// # assert_eq!(artist2.id, 28);
 /// # assert_eq!(artist3.id, 29);
@@ -85,31 +97,181 @@ pub struct Artist {
 
 impl Artist {
 
-    pub(crate) fn new(json: &str) -> Self {
-        serde_json::from_str(&json).unwrap()
+    pub(crate) fn new(json: &str) -> Result<Self, DeezerError> {
+        from_json(json)
     }
 
     /// Returns an `Artist` from an artist id.
     ///
-    /// Doesn't use [`Api`](Api), better suited for single uses.
+    /// Creates a new [`Api`](Api) on every call, better suited for single uses.
     ///
     /// If you need to make a lot of requests, use [`Api`](Api).
-    pub fn get(id: u32) -> Self {
+    pub fn get(id: u32) -> Result<Self, DeezerError> {
+        Api::new().get_artist(id)
+    }
+}
+
+/// Shortened version of [`Track`].
+/// Use [`.get_full()`] for the corresponding [`Track`] struct.
+///
+/// Returned by the artist's top tracks and radio, and by radios.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate deezer_metadata;
+/// # use deezer_metadata::api::Api;
+/// # fn main() {
+/// // Get a new Api Client
+/// let deezer = Api::new();
+///
+/// // Get the artist's 5 top tracks
+/// let top = deezer.get_artist_top(27)
+///     .take(5)
+///     .collect::<Result<Vec<_>, _>>()
+///     .unwrap();
+/// # assert_eq!(top.len(), 5);
+/// # }
+/// ```
+///
+/// [`Track`]: Track
+/// [`.get_full()`]: struct.ArtistTrack.html#method.get_full
+#[derive(Deserialize, Serialize, Debug)]
+pub struct ArtistTrack {
+
+    /// `The track's Deezer id`
+    pub id: u32,
+
+    /// `True if the track is readable in the player for the current user`
+    pub readable: bool,
+
+    /// `The track's full title`
+    pub title: String,
+
+    /// `The track's short title`
+    pub title_short: String,
+
+    /// `The track's version`
+    #[serde(default)]
+    pub title_version: String,
+
+    /// `The url of the track on Deezer`
+    pub link: String,
+
+    /// `The track's duration in seconds`
+    #[serde(rename = "duration")]
+    pub duration_in_seconds: u32,
+
+    /// `The track's Deezer rank`
+    pub rank: u32,
+
+    /// `Whether the track contains explicit lyrics`
+    #[serde(rename = "explicit_lyrics")]
+    pub has_explicit_lyrics: bool,
+
+    /// `The url of track's preview file. This file contains the first 30 seconds of the track`
+    #[serde(default)]
+    #[serde(rename = "preview")]
+    pub preview_url: Option<String>,
+
+    /// `Artist Object`
+    pub artist: AlbumTrackArtist,
+
+    /// `Album Object`
+    pub album: PlaylistTrackAlbum,
+}
 
-        // Get the track api
-        let artist_api = get_artist_api(id);
+impl ArtistTrack {
 
-        // Get the json for the track
-        let mut resp = reqwest::get(&artist_api).unwrap();
-        let json = resp.text().unwrap();
+    /// Returns the corresponding [`Track`](Track) with all the information available,
+    /// through a new uncached [`Api`](Api).
+    pub fn get_full(&self) -> Result<Track, DeezerError> {
+        Track::get(self.id)
+    }
 
-        Self::new(&json)
+    /// Returns the corresponding [`Track`](Track) through the given [`Api`](Api) and its cache.
+    pub fn get_full_with<T: Transport>(&self, api: &Api<T>) -> Result<Track, DeezerError> {
+        api.get_track(self.id)
     }
 }
 
-/// Takes an id and produces the appropriate api url.
-pub(crate) fn get_artist_api(id: u32) -> String {
+/// Shortened version of [`Album`].
+/// Use [`.get_full()`] for the corresponding [`Album`] struct.
+///
+/// [`Album`]: Album
+/// [`.get_full()`]: struct.ArtistAlbum.html#method.get_full
+#[derive(Deserialize, Serialize, Debug)]
+pub struct ArtistAlbum {
+
+    /// `The Deezer album id`
+    pub id: u32,
+
+    /// `The album title`
+    pub title: String,
+
+    /// `The url of the album on Deezer`
+    pub link: String,
+
+    /// `The url of the album's cover.`
+    pub cover: String,
+
+    /// `The url of the album's cover in size small.`
+    pub cover_small: String,
+
+    /// `The url of the album's cover in size medium.`
+    pub cover_medium: String,
+
+    /// `The url of the album's cover in size big.`
+    pub cover_big: String,
+
+    /// `The url of the album's cover in size xl.`
+    pub cover_xl: String,
+
+    /// `The album's first genre id`
+    #[serde(default)]
+    pub genre_id: Option<i32>,
+
+    /// `The number of album's Fans`
+    pub fans: u32,
+
+    /// `The album's release date`
+    pub release_date: String,
 
-    // Construct the api url with the given id
-    "https://api.deezer.com/artist/".to_owned() + &id.to_string()
-}
\ No newline at end of file
+    /// `The record type of the album (EP / ALBUM / etc..)`
+    pub record_type: String,
+
+    /// `API Link to the tracklist of this album`
+    pub tracklist: String,
+
+    /// `Whether the album contains explicit lyrics`
+    #[serde(rename = "explicit_lyrics")]
+    pub has_explicit_lyrics: bool,
+}
+
+impl ArtistAlbum {
+
+    /// Returns the corresponding [`Album`](Album) with all the information available,
+    /// through a new uncached [`Api`](Api).
+    pub fn get_full(&self) -> Result<Album, DeezerError> {
+        Album::get(self.id)
+    }
+
+    /// Returns the corresponding [`Album`](Album) through the given [`Api`](Api) and its cache.
+    pub fn get_full_with<T: Transport>(&self, api: &Api<T>) -> Result<Album, DeezerError> {
+        api.get_album(self.id)
+    }
+}
+
+/// Takes an id and produces the appropriate api path.
+pub(crate) fn get_artist_path(id: u32) -> String {
+
+    // Construct the api path with the given id
+    "artist/".to_owned() + &id.to_string()
+}
+
+/// Takes an id and a relation (`top`, `albums`, ...) and produces the appropriate api path.
+pub(crate) fn get_artist_relation_path(id: u32, relation: &str) -> String {
+
+    // Construct the api path with the given id
+    format!("artist/{}/{}", id, relation)
+}
@@ -1,12 +1,16 @@
 //! Contains the [`Chart`](Chart) struct.
-#[deny(warnings, missing_docs)]
-#[allow(dead_code)]
-
-use serde_json;
-use serde_json::Value;
-use serde::{
-    Deserialize,
-    Deserializer,
+#![deny(warnings, missing_docs)]
+#![allow(dead_code)]
+
+use api::{
+    Api,
+    DEFAULT_BASE_URL,
+};
+use api::errors::DeezerError;
+use api::transport::Transport;
+use api::objects::{
+    deserialize_map,
+    from_json,
 };
 
 use api::objects::user::User;
@@ -15,47 +19,86 @@ use api::objects::album::Album;
 use api::objects::artist::Artist;
 use api::objects::playlist::Playlist;
 
+pub use api::objects::genre::GenrePodcast;
+
+/// Contains the top tracks, albums, artists, playlists and podcasts on Deezer.
+///
+/// # Examples
+///
+/// Get the chart of every genre, or of a single one:
+///
+/// ```rust
+/// # extern crate deezer_metadata;
+/// # use deezer_metadata::api::Api;
+/// # use deezer_metadata::api::objects::chart::Chart;
+/// # fn main() {
+/// let chart = Chart::get().unwrap();
+///
+/// // Get a new Api Client
+/// let deezer = Api::new();
+///
+/// // The chart of the "Pop" genre
+/// let pop = deezer.get_chart_for(132).unwrap();
+///
+/// // Every section only holds the top 10, fetch the top 100 instead
+/// let top_tracks = deezer.get_chart_tracks(132)
+///     .limit(100)
+///     .take(100)
+///     .collect::<Result<Vec<_>, _>>()
+///     .unwrap();
+/// # assert!(!chart.tracks.is_empty());
+/// # assert!(!pop.tracks.is_empty());
+/// # assert_eq!(top_tracks.len(), 100);
+/// # }
+/// ```
 #[derive(Deserialize, Serialize, Debug)]
 pub struct Chart {
 
     /// Vector of ChartTrack objects in the Chart
-    #[serde(deserialize_with = "deserialize_chart")]
+    #[serde(deserialize_with = "deserialize_map")]
     pub tracks: Vec<ChartTrack>,
 
     /// Vector of ChartAlbum objects in the Chart
-    #[serde(deserialize_with = "deserialize_chart")]
+    #[serde(deserialize_with = "deserialize_map")]
     pub albums: Vec<ChartAlbum>,
 
     /// Vector of ChartArtist objects in the Chart
-    #[serde(deserialize_with = "deserialize_chart")]
+    #[serde(deserialize_with = "deserialize_map")]
     pub artists: Vec<ChartArtist>,
 
     /// Vector of Playlist objects in the Chart
-    #[serde(deserialize_with = "deserialize_chart")]
+    #[serde(deserialize_with = "deserialize_map")]
     pub playlists: Vec<ChartPlaylist>,
+
+    /// Vector of GenrePodcast objects in the Chart
+    #[serde(default)]
+    #[serde(deserialize_with = "deserialize_map")]
+    pub podcasts: Vec<GenrePodcast>,
 }
 
 impl Chart {
 
-    pub fn new(json: &str) -> Self {
-        serde_json::from_str(&json).unwrap()
+    /// Decodes a `Chart` from the JSON of a `chart` response, e.g. one stored earlier.
+    pub fn new(json: &str) -> Result<Self, DeezerError> {
+        from_json(json)
     }
 
-    /// Returns the `Chart` for a specified genre.
+    /// Returns the `Chart` across every genre.
     ///
-    /// Doesn't use [`Api`](Api), better suited for single uses.
+    /// Creates a new [`Api`](Api) on every call, better suited for single uses.
     ///
     /// If you need to make a lot of requests, use [`Api`](Api).
-    pub fn get() -> Self {
-
-        // Get the chart api
-        let chart_api = get_chart_api();
-
-        // Get the json for the chart
-        let mut resp = reqwest::get(&chart_api).unwrap();
-        let json = resp.text().unwrap();
+    pub fn get() -> Result<Self, DeezerError> {
+        Api::new().get_chart()
+    }
 
-        Self::new(&json)
+    /// Returns the `Chart` for a specified genre.
+    ///
+    /// Creates a new [`Api`](Api) on every call, better suited for single uses.
+    ///
+    /// If you need to make a lot of requests, use [`Api`](Api).
+    pub fn get_for(genre_id: u32) -> Result<Self, DeezerError> {
+        Api::new().get_chart_for(genre_id)
     }
 }
 
@@ -109,10 +152,16 @@ pub struct ChartTrack {
 
 impl ChartTrack {
 
-    /// Returns the corresponding [`Track`](Track) with all the information available.
-    pub fn get_full(&self) -> Track {
+    /// Returns the corresponding [`Track`](Track) with all the information available,
+    /// through a new uncached [`Api`](Api).
+    pub fn get_full(&self) -> Result<Track, DeezerError> {
         Track::get(self.id)
     }
+
+    /// Returns the corresponding [`Track`](Track) through the given [`Api`](Api) and its cache.
+    pub fn get_full_with<T: Transport>(&self, api: &Api<T>) -> Result<Track, DeezerError> {
+        api.get_track(self.id)
+    }
 }
 
 /// Shortened version of [`Artist`].
@@ -154,10 +203,16 @@ pub struct ChartTrackArtist {
 
 impl ChartTrackArtist {
 
-    /// Returns the corresponding [`Artist`](Artist) with all the information available.
-    pub fn get_full(&self) -> Artist {
+    /// Returns the corresponding [`Artist`](Artist) with all the information available,
+    /// through a new uncached [`Api`](Api).
+    pub fn get_full(&self) -> Result<Artist, DeezerError> {
         Artist::get(self.id)
     }
+
+    /// Returns the corresponding [`Artist`](Artist) through the given [`Api`](Api) and its cache.
+    pub fn get_full_with<T: Transport>(&self, api: &Api<T>) -> Result<Artist, DeezerError> {
+        api.get_artist(self.id)
+    }
 }
 
 
@@ -193,10 +248,16 @@ pub struct ChartTrackAlbum {
 
 impl ChartTrackAlbum {
 
-    /// Returns the corresponding [`Album`](Album) with all the information available.
-    pub fn get_full(&self) -> Album {
+    /// Returns the corresponding [`Album`](Album) with all the information available,
+    /// through a new uncached [`Api`](Api).
+    pub fn get_full(&self) -> Result<Album, DeezerError> {
         Album::get(self.id)
     }
+
+    /// Returns the corresponding [`Album`](Album) through the given [`Api`](Api) and its cache.
+    pub fn get_full_with<T: Transport>(&self, api: &Api<T>) -> Result<Album, DeezerError> {
+        api.get_album(self.id)
+    }
 }
 
 /// Shortened version of [`Album`].
@@ -244,10 +305,16 @@ pub struct ChartAlbum {
 
 impl ChartAlbum {
 
-    /// Returns the corresponding [`Album`](Album) with all the information available.
-    pub fn get_full(&self) -> Album {
+    /// Returns the corresponding [`Album`](Album) with all the information available,
+    /// through a new uncached [`Api`](Api).
+    pub fn get_full(&self) -> Result<Album, DeezerError> {
         Album::get(self.id)
     }
+
+    /// Returns the corresponding [`Album`](Album) through the given [`Api`](Api) and its cache.
+    pub fn get_full_with<T: Transport>(&self, api: &Api<T>) -> Result<Album, DeezerError> {
+        api.get_album(self.id)
+    }
 }
 
 /// Shortened version of [`Artist`].
@@ -289,10 +356,16 @@ pub struct ChartAlbumArtist {
 
 impl ChartAlbumArtist {
 
-    /// Returns the corresponding [`Artist`](Artist) with all the information available.
-    pub fn get_full(&self) -> Artist {
+    /// Returns the corresponding [`Artist`](Artist) with all the information available,
+    /// through a new uncached [`Api`](Api).
+    pub fn get_full(&self) -> Result<Artist, DeezerError> {
         Artist::get(self.id)
     }
+
+    /// Returns the corresponding [`Artist`](Artist) through the given [`Api`](Api) and its cache.
+    pub fn get_full_with<T: Transport>(&self, api: &Api<T>) -> Result<Artist, DeezerError> {
+        api.get_artist(self.id)
+    }
 }
 
 /// Shortened version of [`Artist`].
@@ -337,10 +410,16 @@ pub struct ChartArtist {
 
 impl ChartArtist {
 
-    /// Returns the corresponding [`Artist`](Artist) with all the information available.
-    pub fn get_full(&self) -> Artist {
+    /// Returns the corresponding [`Artist`](Artist) with all the information available,
+    /// through a new uncached [`Api`](Api).
+    pub fn get_full(&self) -> Result<Artist, DeezerError> {
         Artist::get(self.id)
     }
+
+    /// Returns the corresponding [`Artist`](Artist) through the given [`Api`](Api) and its cache.
+    pub fn get_full_with<T: Transport>(&self, api: &Api<T>) -> Result<Artist, DeezerError> {
+        api.get_artist(self.id)
+    }
 }
 
 /// Shortened version of [`Playlist`].
@@ -352,7 +431,7 @@ impl ChartArtist {
 pub struct ChartPlaylist {
 
     /// The playlist's Deezer id
-    pub id: u32,
+    pub id: u64,
 
     /// The playlist's title
     pub title: String,
@@ -389,10 +468,16 @@ pub struct ChartPlaylist {
 
 impl ChartPlaylist {
 
-    /// Returns the corresponding [`Playlist`](Playlist) with all the information available.
-    pub fn get_full(&self) -> Playlist {
+    /// Returns the corresponding [`Playlist`](Playlist) with all the information available,
+    /// through a new uncached [`Api`](Api).
+    pub fn get_full(&self) -> Result<Playlist, DeezerError> {
         Playlist::get(self.id)
     }
+
+    /// Returns the corresponding [`Playlist`](Playlist) through the given [`Api`](Api) and its cache.
+    pub fn get_full_with<T: Transport>(&self, api: &Api<T>) -> Result<Playlist, DeezerError> {
+        api.get_playlist(self.id)
+    }
 }
 
 /// Shortened version of [`User`].
@@ -412,29 +497,42 @@ pub struct ChartPlaylistUser {
 
 impl ChartPlaylistUser {
 
-    /// Returns the corresponding [`User`](User) with all the information available.
-    pub fn get_full(&self) -> User {
+    /// Returns the corresponding [`User`](User) with all the information available,
+    /// through a new uncached [`Api`](Api).
+    pub fn get_full(&self) -> Result<User, DeezerError> {
         User::get(self.id)
     }
-}
-
-fn deserialize_chart<'der, T, D>(de: D) -> Result<Vec<T>, D::Error>
-    where D: Deserializer<'der>, for<'de> T: Deserialize<'de>
-{
-    let helper: Value = Deserialize::deserialize(de)?;
-    let mut return_value = Vec::<T>::new();
 
-    for object in helper.get("data").unwrap().as_array().unwrap() {
-        let object: T = serde_json::from_value(object.clone()).unwrap();
-        return_value.push(object);
+    /// Returns the corresponding [`User`](User) through the given [`Api`](Api) and its cache.
+    pub fn get_full_with<T: Transport>(&self, api: &Api<T>) -> Result<User, DeezerError> {
+        api.get_user(self.id)
     }
-
-    Ok(return_value)
 }
 
-/// Takes an id and produces the appropriate api url.
+/// Produces the appropriate api url on [`DEFAULT_BASE_URL`](DEFAULT_BASE_URL).
 pub fn get_chart_api() -> String {
 
     // Construct the api url
-    "https://api.deezer.com/chart".to_owned()
-}
\ No newline at end of file
+    format!("{}/{}", DEFAULT_BASE_URL, get_chart_path())
+}
+
+/// Produces the appropriate api path.
+pub(crate) fn get_chart_path() -> String {
+
+    // Construct the api path
+    "chart".to_owned()
+}
+
+/// Takes a genre id and produces the appropriate api path.
+pub(crate) fn get_chart_for_path(genre_id: u32) -> String {
+
+    // Construct the api path with the given genre id
+    "chart/".to_owned() + &genre_id.to_string()
+}
+
+/// Takes a genre id and a section (`tracks`, `albums`, ...) and produces the appropriate api path.
+pub(crate) fn get_chart_section_path(genre_id: u32, section: &str) -> String {
+
+    // Construct the api path with the given genre id
+    format!("chart/{}/{}", genre_id, section)
+}
@@ -1,7 +1,25 @@
 //! Contains the [`Comment`](Comment) struct.
 //! Also contains a few other helper structs.
-#[deny(warnings, missing_docs)]
-#[allow(dead_code)]
+#![deny(warnings, missing_docs)]
+#![allow(dead_code)]
+
+use api::Api;
+use api::errors::DeezerError;
+use api::transport::Transport;
+use api::objects::from_json;
+
+use std::convert::TryFrom;
+use std::fmt;
+
+use serde::de;
+use serde::ser::SerializeStruct;
+use serde::{
+    Deserialize,
+    Deserializer,
+    Serialize,
+    Serializer,
+};
+use serde_json::Value;
 
 use api::objects::user::User;
 
@@ -16,7 +34,7 @@ use api::objects::user::User;
 /// # use deezer_metadata::api::objects::comment::Comment;
 /// # fn main() {
 /// // Pass the comment id into the 'get' method
-/// let comment = Comment::get(4179157801);
+/// let comment = Comment::get(4179157801).unwrap();
 /// # assert_eq!(comment.id, 4179157801);
 /// # }
 /// ```
@@ -32,7 +50,7 @@ use api::objects::user::User;
 /// let deezer = Api::new();
 ///
 /// // Get as many comments as you want with the same Api Client
-/// let comment1 = deezer.get_comment(4179157801);
+/// let comment1 = deezer.get_comment(4179157801).unwrap();
 /// # assert_eq!(comment1.id, 4179157801);
 /// # }
 ///
@@ -49,9 +67,9 @@ pub struct Comment {
     /// The date the comment was posted
     pub date: u32,
 
-    /// Object the comment belongs to, containing: id, type.
-    /// Type can be "artist", "album" or "playlist".
-    object: CommentParent,
+    /// Object the comment belongs to
+    #[serde(rename = "object")]
+    pub parent: CommentParent,
 
     /// User this comment belongs to
     pub author: CommentAuthor,
@@ -59,25 +77,17 @@ pub struct Comment {
 
 impl Comment {
 
-    pub(crate) fn new(json: &str) -> Self {
-        serde_json::from_str(&json).unwrap()
+    pub(crate) fn new(json: &str) -> Result<Self, DeezerError> {
+        from_json(json)
     }
 
     /// Returns a `Comment` from a comment id.
     ///
-    /// Doesn't use [`Api`](Api), better suited for single uses.
+    /// Creates a new [`Api`](Api) on every call, better suited for single uses.
     ///
     /// If you need to make a lot of requests, use [`Api`](Api).
-    pub fn get(id: u32) -> Self {
-
-        // Get the track api
-        let comment_api = get_comment_api(id);
-
-        // Get the json for the track
-        let mut resp = reqwest::get(&comment_api).unwrap();
-        let json = resp.text().unwrap();
-
-        Self::new(&json)
+    pub fn get(id: u32) -> Result<Self, DeezerError> {
+        Api::new().get_comment(id)
     }
 }
 
@@ -116,24 +126,133 @@ pub struct CommentAuthor {
 
 impl CommentAuthor {
 
-    /// Returns the corresponding [`User`](User) with all the information available.
-    pub fn get_full(&self) -> User {
+    /// Returns the corresponding [`User`](User) with all the information available,
+    /// through a new uncached [`Api`](Api).
+    pub fn get_full(&self) -> Result<User, DeezerError> {
         User::get(self.id)
     }
+
+    /// Returns the corresponding [`User`](User) through the given [`Api`](Api) and its cache.
+    pub fn get_full_with<T: Transport>(&self, api: &Api<T>) -> Result<User, DeezerError> {
+        api.get_user(self.id)
+    }
 }
 
-#[derive(Deserialize, Serialize, Debug)]
-struct CommentParent {
+/// The object a [`Comment`](Comment) was posted on.
+///
+/// # Examples
+///
+/// Deezer sends the id either as a number or as a string:
+///
+/// ```rust
+/// # extern crate deezer_metadata;
+/// # extern crate serde_json;
+/// # use deezer_metadata::api::objects::comment::CommentParent;
+/// # fn main() {
+/// let album: CommentParent = serde_json::from_str(r#"{"id": 302127, "type": "album"}"#).unwrap();
+/// assert_eq!(album, CommentParent::Album(302127));
+///
+/// let playlist: CommentParent = serde_json::from_str(r#"{"id": "13133771063", "type": "playlist"}"#).unwrap();
+/// assert_eq!(playlist, CommentParent::Playlist(13133771063));
+///
+/// // Comments can't be posted on tracks
+/// assert!(serde_json::from_str::<CommentParent>(r#"{"id": 3135556, "type": "track"}"#).is_err());
+///
+/// // Always written back with a numeric id
+/// let json = serde_json::to_string(&album).unwrap();
+/// assert_eq!(json, r#"{"id":302127,"type":"album"}"#);
+/// assert_eq!(serde_json::from_str::<CommentParent>(&json).unwrap(), album);
+/// # }
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CommentParent {
 
-    id: String,
+    /// The album with the given id
+    Album(u32),
 
-    #[serde(rename = "type")]
-    object_type: String,
+    /// The artist with the given id
+    Artist(u32),
+
+    /// The playlist with the given id
+    Playlist(u64),
 }
 
-/// Takes an id and produces the appropriate api url.
-pub(crate) fn get_comment_api(id: u32) -> String {
+impl CommentParent {
 
-    // Construct the api url with the given id
-    "https://api.deezer.com/comment/".to_owned() + &id.to_string()
-}
\ No newline at end of file
+    /// Returns the Deezer id of the object.
+    pub fn id(&self) -> u64 {
+        match *self {
+            CommentParent::Album(id) | CommentParent::Artist(id) => id.into(),
+            CommentParent::Playlist(id) => id,
+        }
+    }
+
+    /// Returns the type of the object, as named by Deezer.
+    pub fn object_type(&self) -> &'static str {
+        match *self {
+            CommentParent::Album(_) => "album",
+            CommentParent::Artist(_) => "artist",
+            CommentParent::Playlist(_) => "playlist",
+        }
+    }
+}
+
+impl fmt::Display for CommentParent {
+
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}/{}", self.object_type(), self.id())
+    }
+}
+
+impl<'de> Deserialize<'de> for CommentParent {
+
+    /// Deezer sends `{"id": "302127", "type": "album"}`, the id being a string or a number.
+    fn deserialize<D>(de: D) -> Result<Self, D::Error>
+        where D: Deserializer<'de>
+    {
+        #[derive(Deserialize)]
+        struct Helper {
+            id: Value,
+            #[serde(rename = "type")]
+            object_type: String,
+        }
+
+        let helper = Helper::deserialize(de)?;
+
+        let id = match helper.id {
+            Value::Number(ref id) => id.as_u64(),
+            Value::String(ref id) => id.parse().ok(),
+            _ => None,
+        };
+
+        let invalid = || de::Error::custom(format!("invalid comment parent id: {}", helper.id));
+        let id = id.ok_or_else(invalid)?;
+
+        // Recent playlists have ids past `u32::MAX`, albums and artists don't
+        match helper.object_type.as_str() {
+            "album" => u32::try_from(id).map(CommentParent::Album).map_err(|_| invalid()),
+            "artist" => u32::try_from(id).map(CommentParent::Artist).map_err(|_| invalid()),
+            "playlist" => Ok(CommentParent::Playlist(id)),
+            other => Err(de::Error::unknown_variant(other, &["album", "artist", "playlist"])),
+        }
+    }
+}
+
+impl Serialize for CommentParent {
+
+    fn serialize<S>(&self, ser: S) -> Result<S::Ok, S::Error>
+        where S: Serializer
+    {
+        let mut state = ser.serialize_struct("CommentParent", 2)?;
+        state.serialize_field("id", &self.id())?;
+        state.serialize_field("type", self.object_type())?;
+        state.end()
+    }
+}
+
+/// Takes an id and produces the appropriate api path.
+pub(crate) fn get_comment_path(id: u32) -> String {
+
+    // Construct the api path with the given id
+    "comment/".to_owned() + &id.to_string()
+}
@@ -1,6 +1,19 @@
 //! Contains the [`Editorial`](Editorial) struct.
-#[deny(warnings, missing_docs)]
-#[allow(dead_code)]
+//! Also contains the [`EditorialAlbum`](EditorialAlbum) helper struct.
+#![deny(warnings, missing_docs)]
+#![allow(dead_code)]
+
+use api::{
+    Api,
+    DEFAULT_BASE_URL,
+};
+use api::errors::DeezerError;
+use api::transport::Transport;
+use api::objects::from_json;
+use api::objects::album::{
+    Album,
+    AlbumArtist,
+};
 
 /// Contains all the information provided for an Editorial.
 ///
@@ -13,7 +26,7 @@
 /// # use deezer_metadata::api::objects::editorial::Editorial;
 /// # fn main() {
 /// // Pass the editorial id into the 'get' method
-/// let editorial = Editorial::get(0);
+/// let editorial = Editorial::get(0).unwrap();
 /// # assert_eq!(editorial.id, 0);
 /// # }
 /// ```
@@ -29,9 +42,9 @@
 /// let deezer = Api::new();
 ///
 /// // Get as many editorials as you want with the same Api Client
-/// let editorial1 = deezer.get_editorial(0);
-/// let editorial2 = deezer.get_editorial(132);
-/// let editorial3 = deezer.get_editorial(152);
+/// let editorial1 = deezer.get_editorial(0).unwrap();
+/// let editorial2 = deezer.get_editorial(132).unwrap();
+/// let editorial3 = deezer.get_editorial(152).unwrap();
 /// # assert_eq!(editorial1.id, 0);
 /// # assert_eq!(editorial2.id, 132);
 /// # assert_eq!(editorial3.id, 152);
@@ -65,38 +78,139 @@ pub struct Editorial {
 
 impl Editorial {
 
-    pub fn new(json: &str) -> Self {
-        serde_json::from_str(&json).unwrap()
+    /// Decodes an `Editorial` from the JSON of an `editorial/{id}` response.
+    pub fn new(json: &str) -> Result<Self, DeezerError> {
+        from_json(json)
     }
 
     /// Returns an `Editorial` from an editorial id.
     ///
-    /// Doesn't use [`Api`](Api), better suited for single uses.
+    /// Creates a new [`Api`](Api) on every call, better suited for single uses.
     ///
     /// If you need to make a lot of requests, use [`Api`](Api).
-    pub fn get(id: u32) -> Self {
+    pub fn get(id: u32) -> Result<Self, DeezerError> {
+        Api::new().get_editorial(id)
+    }
+
+    /// Returns every `Editorial` available on Deezer.
+    ///
+    /// Creates a new [`Api`](Api) on every call, better suited for single uses.
+    ///
+    /// If you need to make a lot of requests, use [`Api`](Api).
+    pub fn all() -> Result<Vec<Self>, DeezerError> {
+        Api::new().get_editorials()
+    }
+}
 
-        // Get the track api
-        let editorial_api = get_editorial_api(id);
+/// Shortened version of [`Album`].
+/// Use [`.get_full()`] for the corresponding [`Album`] struct.
+///
+/// Returned by the editorial's selection and releases.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate deezer_metadata;
+/// # use deezer_metadata::api::Api;
+/// # fn main() {
+/// // Get a new Api Client
+/// let deezer = Api::new();
+///
+/// // Get the 10 latest releases of the "Pop" editorial
+/// let releases = deezer.get_editorial_releases(132)
+///     .take(10)
+///     .collect::<Result<Vec<_>, _>>()
+///     .unwrap();
+/// # assert_eq!(releases.len(), 10);
+/// # }
+/// ```
+///
+/// [`Album`]: Album
+/// [`.get_full()`]: struct.EditorialAlbum.html#method.get_full
+#[derive(Deserialize, Serialize, Debug)]
+pub struct EditorialAlbum {
 
-        // Get the json for the track
-        let mut resp = reqwest::get(&editorial_api).unwrap();
-        let json = resp.text().unwrap();
+    /// `The Deezer album id`
+    pub id: u32,
 
-        Self::new(&json)
-    }
+    /// `The album title`
+    pub title: String,
 
-    pub fn all() -> Vec<Self> {
+    /// `The url of the album on Deezer`
+    #[serde(default)]
+    pub link: Option<String>,
 
-        // TODO: implement in `Api` as well
-        // TODO: Change documentation for the struct after implementing
-        unimplemented!();
+    /// `The url of the album's cover.`
+    pub cover: String,
+
+    /// `The url of the album's cover in size small.`
+    pub cover_small: String,
+
+    /// `The url of the album's cover in size medium.`
+    pub cover_medium: String,
+
+    /// `The url of the album's cover in size big.`
+    pub cover_big: String,
+
+    /// `The url of the album's cover in size xl.`
+    pub cover_xl: String,
+
+    /// `The album's release date`
+    #[serde(default)]
+    pub release_date: Option<String>,
+
+    /// `The record type of the album (EP / ALBUM / etc..)`
+    pub record_type: String,
+
+    /// `API Link to the tracklist of this album`
+    pub tracklist: String,
+
+    /// `Whether the album contains explicit lyrics`
+    #[serde(rename = "explicit_lyrics")]
+    pub has_explicit_lyrics: bool,
+
+    /// `Returns an AlbumArtist object of the artist this album belongs to`
+    pub artist: AlbumArtist,
+}
+
+impl EditorialAlbum {
+
+    /// Returns the corresponding [`Album`](Album) with all the information available,
+    /// through a new uncached [`Api`](Api).
+    pub fn get_full(&self) -> Result<Album, DeezerError> {
+        Album::get(self.id)
     }
+
+    /// Returns the corresponding [`Album`](Album) through the given [`Api`](Api) and its cache.
+    pub fn get_full_with<T: Transport>(&self, api: &Api<T>) -> Result<Album, DeezerError> {
+        api.get_album(self.id)
+    }
+}
+
+/// Produces the appropriate api path for the list of editorials.
+pub(crate) fn get_editorials_path() -> String {
+
+    // Construct the api path
+    "editorial".to_owned()
 }
 
-/// Takes an id and produces the appropriate api url.
+/// Takes an id and produces the appropriate api url on [`DEFAULT_BASE_URL`](DEFAULT_BASE_URL).
 pub fn get_editorial_api(id: u32) -> String {
 
     // Construct the api url with the given id
-    "https://api.deezer.com/editorial/".to_owned() + &id.to_string()
-}
\ No newline at end of file
+    format!("{}/{}", DEFAULT_BASE_URL, get_editorial_path(id))
+}
+
+/// Takes an id and produces the appropriate api path.
+pub(crate) fn get_editorial_path(id: u32) -> String {
+
+    // Construct the api path with the given id
+    "editorial/".to_owned() + &id.to_string()
+}
+
+/// Takes an id and a relation (`selection`, `charts`, ...) and produces the appropriate api path.
+pub(crate) fn get_editorial_relation_path(id: u32, relation: &str) -> String {
+
+    // Construct the api path with the given id
+    format!("editorial/{}/{}", id, relation)
+}
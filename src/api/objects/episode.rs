@@ -0,0 +1,164 @@
+//! Contains the [`Episode`](Episode) struct.
+//! Also contains the [`EpisodePodcast`](EpisodePodcast) helper struct.
+#![deny(warnings, missing_docs)]
+#![allow(dead_code)]
+
+use api::Api;
+use api::errors::DeezerError;
+use api::transport::Transport;
+use api::objects::from_json;
+use api::objects::podcast::Podcast;
+
+/// Contains all the information provided for a podcast Episode.
+///
+/// # Examples
+///
+/// For single uses, you can get an episode using the `Episode` struct:
+///
+/// ```rust
+/// # extern crate deezer_metadata;
+/// # use deezer_metadata::api::objects::episode::Episode;
+/// # fn main() {
+/// // Pass the episode id into the 'get' method
+/// let episode = Episode::get(526673645).unwrap();
+/// # assert_eq!(episode.id, 526673645);
+/// # }
+/// ```
+///
+/// Or, you can use the [`Api`](Api) struct for multiple requests:
+///
+/// ```rust
+/// # extern crate deezer_metadata;
+/// # use deezer_metadata::api::Api;
+/// # use deezer_metadata::api::objects::episode::Episode;
+/// # fn main() {
+/// // Get a new Api Client
+/// let deezer = Api::new();
+///
+/// // Get as many episodes as you want with the same Api Client
+/// let episode = deezer.get_episode(526673645).unwrap();
+///
+/// // Every episode knows which podcast it belongs to
+/// let podcast = deezer.get_podcast(episode.podcast.id).unwrap();
+/// # assert_eq!(episode.id, 526673645);
+/// # assert_eq!(podcast.id, episode.podcast.id);
+/// # }
+///
+/// ```
+#[derive(Deserialize, Serialize, Debug)]
+pub struct Episode {
+
+    /// The episode's Deezer id
+    pub id: u32,
+
+    /// The episode's title
+    pub title: String,
+
+    /// The episode's description
+    pub description: String,
+
+    /// If the episode is available or not
+    pub available: bool,
+
+    /// The episode's release date
+    pub release_date: String,
+
+    /// The episode's duration in seconds
+    #[serde(rename = "duration")]
+    pub duration_in_seconds: u32,
+
+    /// The url of the episode on Deezer
+    pub link: String,
+
+    /// The share link of the episode on Deezer
+    #[serde(rename = "share")]
+    pub share_link: String,
+
+    /// The url of the episode's cover
+    pub picture: String,
+
+    /// The url of the episode's cover in size small
+    pub picture_small: String,
+
+    /// The url of the episode's cover in size medium
+    pub picture_medium: String,
+
+    /// The url of the episode's cover in size big
+    pub picture_big: String,
+
+    /// The url of the episode's cover in size xl
+    pub picture_xl: String,
+
+    /// The podcast this episode belongs to
+    pub podcast: EpisodePodcast,
+}
+
+impl Episode {
+
+    pub(crate) fn new(json: &str) -> Result<Self, DeezerError> {
+        from_json(json)
+    }
+
+    /// Returns an `Episode` from an episode id.
+    ///
+    /// Creates a new [`Api`](Api) on every call, better suited for single uses.
+    ///
+    /// If you need to make a lot of requests, use [`Api`](Api).
+    pub fn get(id: u32) -> Result<Self, DeezerError> {
+        Api::new().get_episode(id)
+    }
+}
+
+/// Shortened version of [`Podcast`].
+/// Use [`.get_full()`] for the corresponding [`Podcast`] struct.
+///
+/// [`Podcast`]: Podcast
+/// [`.get_full()`]: struct.EpisodePodcast.html#method.get_full
+#[derive(Deserialize, Serialize, Debug)]
+pub struct EpisodePodcast {
+
+    /// The podcast's Deezer id
+    pub id: u32,
+
+    /// The podcast's title
+    pub title: String,
+
+    /// The url of the podcast on Deezer
+    pub link: String,
+
+    /// The url of the podcast's cover
+    pub picture: String,
+
+    /// The url of the podcast's cover in size small
+    pub picture_small: String,
+
+    /// The url of the podcast's cover in size medium
+    pub picture_medium: String,
+
+    /// The url of the podcast's cover in size big
+    pub picture_big: String,
+
+    /// The url of the podcast's cover in size xl
+    pub picture_xl: String,
+}
+
+impl EpisodePodcast {
+
+    /// Returns the corresponding [`Podcast`](Podcast) with all the information available,
+    /// through a new uncached [`Api`](Api).
+    pub fn get_full(&self) -> Result<Podcast, DeezerError> {
+        Podcast::get(self.id)
+    }
+
+    /// Returns the corresponding [`Podcast`](Podcast) through the given [`Api`](Api) and its cache.
+    pub fn get_full_with<T: Transport>(&self, api: &Api<T>) -> Result<Podcast, DeezerError> {
+        api.get_podcast(self.id)
+    }
+}
+
+/// Takes an id and produces the appropriate api path.
+pub(crate) fn get_episode_path(id: u32) -> String {
+
+    // Construct the api path with the given id
+    "episode/".to_owned() + &id.to_string()
+}
@@ -1,6 +1,16 @@
 //! Contains the [`Genre`](Genre) struct.
-#[deny(warnings, missing_docs)]
-#[allow(dead_code)]
+//! Also contains a few other helper structs.
+#![deny(warnings, missing_docs)]
+#![allow(dead_code)]
+
+use api::Api;
+use api::errors::DeezerError;
+use api::transport::Transport;
+use api::objects::from_json;
+use api::objects::artist::Artist;
+
+/// Podcasts listed under a genre come with every field of a [`Podcast`](../podcast/struct.Podcast.html).
+pub use api::objects::podcast::Podcast as GenrePodcast;
 
 /// Contains all the information provided for a Genre.
 ///
@@ -13,7 +23,7 @@
 /// # use deezer_metadata::api::objects::genre::Genre;
 /// # fn main() {
 /// // Pass the genre id into the 'get' method
-/// let genre = Genre::get(0);
+/// let genre = Genre::get(0).unwrap();
 /// # assert_eq!(genre.id, 0);
 /// # }
 /// ```
@@ -29,9 +39,9 @@
 /// let deezer = Api::new();
 ///
 /// // Get as many albums as you want with the same Api Client
-/// let genre1 = deezer.get_genre(0);
-/// let genre2 = deezer.get_genre(132);
-/// let genre3 = deezer.get_genre(165);
+/// let genre1 = deezer.get_genre(0).unwrap();
+/// let genre2 = deezer.get_genre(132).unwrap();
+/// let genre3 = deezer.get_genre(165).unwrap();
 /// # assert_eq!(genre1.id, 0);
 /// # assert_eq!(genre2.id, 132);
 /// # assert_eq!(genre3.id, 165);
@@ -65,31 +75,97 @@ pub struct Genre {
 
 impl Genre {
 
-    pub(crate) fn new(json: &str) -> Self {
-        serde_json::from_str(&json).unwrap()
+    pub(crate) fn new(json: &str) -> Result<Self, DeezerError> {
+        from_json(json)
     }
 
     /// Returns a `Genre` from a genre id.
     ///
-    /// Doesn't use [`Api`](Api), better suited for single uses.
+    /// Creates a new [`Api`](Api) on every call, better suited for single uses.
     ///
     /// If you need to make a lot of requests, use [`Api`](Api).
-    pub fn get(id: u32) -> Self {
+    pub fn get(id: u32) -> Result<Self, DeezerError> {
+        Api::new().get_genre(id)
+    }
 
-        // Get the track api
-        let genre_api = get_genre_api(id);
+    /// Returns every `Genre` available on Deezer.
+    ///
+    /// Creates a new [`Api`](Api) on every call, better suited for single uses.
+    ///
+    /// If you need to make a lot of requests, use [`Api`](Api).
+    pub fn all() -> Result<Vec<Self>, DeezerError> {
+        Api::new().get_genres()
+    }
+}
 
-        // Get the json for the track
-        let mut resp = reqwest::get(&genre_api).unwrap();
-        let json = resp.text().unwrap();
+/// Shortened version of [`Artist`].
+/// Use [`.get_full()`] for the corresponding [`Artist`] struct.
+///
+/// [`Artist`]: Artist
+/// [`.get_full()`]: struct.GenreArtist.html#method.get_full
+#[derive(Deserialize, Serialize, Debug)]
+pub struct GenreArtist {
 
-        Self::new(&json)
+    /// `The artist's Deezer id`
+    pub id: u32,
+
+    /// `The artist's name`
+    pub name: String,
+
+    /// `The url of the artist picture.`
+    pub picture: String,
+
+    /// `The url of the artist picture in size small`
+    pub picture_small: String,
+
+    /// `The url of the artist picture in size medium`
+    pub picture_medium: String,
+
+    /// `The url of the artist picture in size big`
+    pub picture_big: String,
+
+    /// `The url of the artist picture in size xl`
+    pub picture_xl: String,
+
+    /// `True if the artist has a smartradio`
+    #[serde(rename = "radio")]
+    pub has_radio: bool,
+
+    /// `API Link to the top of this artist`
+    pub tracklist: String,
+}
+
+impl GenreArtist {
+
+    /// Returns the corresponding [`Artist`](Artist) with all the information available,
+    /// through a new uncached [`Api`](Api).
+    pub fn get_full(&self) -> Result<Artist, DeezerError> {
+        Artist::get(self.id)
     }
+
+    /// Returns the corresponding [`Artist`](Artist) through the given [`Api`](Api) and its cache.
+    pub fn get_full_with<T: Transport>(&self, api: &Api<T>) -> Result<Artist, DeezerError> {
+        api.get_artist(self.id)
+    }
+}
+
+/// Takes an id and produces the appropriate api path.
+pub(crate) fn get_genre_path(id: u32) -> String {
+
+    // Construct the api path with the given id
+    "genre/".to_owned() + &id.to_string()
 }
 
-/// Takes an id and produces the appropriate api url.
-pub(crate) fn get_genre_api(id: u32) -> String {
+/// Produces the appropriate api path for the list of genres.
+pub(crate) fn get_genres_path() -> String {
 
-    // Construct the api url with the given id
-    "https://api.deezer.com/genre/".to_owned() + &id.to_string()
-}
\ No newline at end of file
+    // Construct the api path
+    "genre".to_owned()
+}
+
+/// Takes an id and a relation (`artists`, `radios`, ...) and produces the appropriate api path.
+pub(crate) fn get_genre_relation_path(id: u32, relation: &str) -> String {
+
+    // Construct the api path with the given id
+    format!("genre/{}/{}", id, relation)
+}
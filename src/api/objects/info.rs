@@ -1,14 +1,14 @@
 //! Contains the [`Info`](Info) struct.
 //! Also contains the helper struct [`Offer`](Offer).
-#[deny(warnings, missing_docs)]
-#[allow(dead_code)]
-
-use serde_json;
-use serde_json::Value;
-use serde::{
-    Deserialize,
-    Deserializer,
+#![deny(warnings, missing_docs)]
+#![allow(dead_code)]
+
+use api::{
+    Api,
+    DEFAULT_BASE_URL,
 };
+use api::errors::DeezerError;
+use api::objects::from_json;
 
 /// Contains all the information about the API in the current country.
 ///
@@ -20,7 +20,7 @@ use serde::{
 /// # extern crate deezer_metadata;
 /// # use deezer_metadata::api::objects::info::Info;
 /// # fn main() {
-/// let info = Info::get();
+/// let info = Info::get().unwrap();
 /// # }
 /// ```
 ///
@@ -37,9 +37,9 @@ use serde::{
 /// let deezer = Api::new();
 ///
 /// // Make as many Api requests as you want with the same Client
-/// let info = deezer.get_info();
-/// let track = deezer.get_track(912486);
-/// let album = deezer.get_album(302127);
+/// let info = deezer.get_info().unwrap();
+/// let track = deezer.get_track(912486).unwrap();
+/// let album = deezer.get_album(302127).unwrap();
 /// # assert_eq!(track.id, 912486);
 /// # assert_eq!(album.id, 302127);
 /// # }
@@ -58,65 +58,68 @@ pub struct Info {
     pub open: bool,
 
     /// An array of available offers in the current country
-    #[serde(deserialize_with = "deserialize_offers")]
     pub offers: Vec<Offer>
 }
 
 /// Contains all the information provided for an Offer.
 #[derive(Deserialize, Serialize, Debug)]
 pub struct Offer {
+
+    /// The offer's Deezer id
     pub id: u32,
+
+    /// The offer's name
     pub name: String,
+
+    /// The offer's price
     pub amount: String,
+
+    /// The currency of the offer's price
     pub currency: String,
+
+    /// The offer's price, formatted for display
     pub displayed_amount: String,
+
+    /// The url of the offer's terms and conditions
     pub tc: String,
+
+    /// The offer's terms and conditions, as html
     pub tc_html: String,
+
+    /// The offer's terms and conditions, as plain text
     pub tc_txt: String,
+
+    /// The offer's trial duration
     pub try_and_buy: u32,
 }
 
 impl Info {
 
-    pub fn new(json: &str) -> Self {
-        serde_json::from_str(&json).unwrap()
+    /// Decodes an `Info` from the JSON of an `infos` response, e.g. one kept from a previous run.
+    pub fn new(json: &str) -> Result<Self, DeezerError> {
+        from_json(json)
     }
 
     /// Returns `Info`.
     ///
-    /// Doesn't use [`Api`](Api), better suited for single uses.
+    /// Creates a new [`Api`](Api) on every call, better suited for single uses.
     ///
     /// If you need to make a lot of requests, use [`Api`](Api).
-    pub fn get() -> Self {
-
-        // Get the info api
-        let info_api = get_info_api();
-
-        // Get the json for the info
-        let mut resp = reqwest::get(&info_api).unwrap();
-        let json = resp.text().unwrap();
-
-        Self::new(&json)
+    pub fn get() -> Result<Self, DeezerError> {
+        Api::new().get_info()
     }
 }
 
-fn deserialize_offers<'der, D>(de: D) -> Result<Vec<Offer>, D::Error>
-    where D: Deserializer<'der>
-{
-    let helper: Value = Deserialize::deserialize(de)?;
-    let mut return_value = Vec::<Offer>::new();
-
-    for object in helper.as_array().unwrap() {
-        let offer: Offer = serde_json::from_value(object.clone()).unwrap();
-        return_value.push(offer);
-    }
+/// Produces the appropriate api url on [`DEFAULT_BASE_URL`](DEFAULT_BASE_URL).
+pub fn get_info_api() -> String {
 
-    Ok(return_value)
+    // Construct the api url
+    format!("{}/{}", DEFAULT_BASE_URL, get_info_path())
 }
 
-/// Takes an id and produces the appropriate api url.
-pub fn get_info_api() -> String {
+/// Produces the appropriate api path.
+pub(crate) fn get_info_path() -> String {
 
-    // Construct the api url
-    "https://api.deezer.com/infos".to_owned()
-}
\ No newline at end of file
+    // Construct the api path
+    "infos".to_owned()
+}
@@ -0,0 +1,260 @@
+//! Contains the [`DeezerRef`](DeezerRef) enum parsed from Deezer links.
+//! Also contains the [`DeezerObject`](DeezerObject) enum a reference resolves to.
+#![deny(warnings, missing_docs)]
+#![allow(dead_code)]
+
+use std::convert::TryFrom;
+use std::fmt;
+use std::str::FromStr;
+
+use url::Url;
+
+use api::errors::DeezerError;
+use api::objects::album::Album;
+use api::objects::artist::Artist;
+use api::objects::episode::Episode;
+use api::objects::playlist::Playlist;
+use api::objects::podcast::Podcast;
+use api::objects::radio::Radio;
+use api::objects::track::Track;
+use api::objects::user::User;
+
+/// A reference to a Deezer object, as found in the links users share.
+///
+/// Parses website urls (with or without a language, e.g. `/fr/album/302127`),
+/// share links with their `utm` parameters and `deezer://` uris.
+///
+/// Short links (`deezer.page.link`, `link.deezer.com`) have to be followed first,
+/// use [`Api::parse_link`] for those.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate deezer_metadata;
+/// # use deezer_metadata::api::errors::DeezerError;
+/// # use deezer_metadata::api::objects::link::DeezerRef;
+/// # fn main() {
+/// let album: DeezerRef = "https://www.deezer.com/fr/album/302127".parse().unwrap();
+/// assert_eq!(album, DeezerRef::Album(302127));
+///
+/// let track: DeezerRef = "https://www.deezer.com/track/3135556?utm_source=deezer&utm_medium=web"
+///     .parse()
+///     .unwrap();
+/// assert_eq!(track, DeezerRef::Track(3135556));
+///
+/// let artist: DeezerRef = "deezer://www.deezer.com/artist/27".parse().unwrap();
+/// assert_eq!(artist.to_string(), "artist/27");
+///
+/// // Recent playlists have ids past `u32::MAX`
+/// let playlist: DeezerRef = "https://www.deezer.com/playlist/13133771063".parse().unwrap();
+/// assert_eq!(playlist, DeezerRef::Playlist(13133771063));
+///
+/// // Ids too large for their type are told apart from links that aren't Deezer's
+/// match "https://www.deezer.com/track/13133771063".parse::<DeezerRef>() {
+///     Err(DeezerError::IdOutOfRange { id, .. }) => assert_eq!(id, "13133771063"),
+///     other => panic!("expected IdOutOfRange, got {:?}", other),
+/// }
+///
+/// match "https://www.example.com/playlist/908622995".parse::<DeezerRef>() {
+///     Err(DeezerError::InvalidLink(_)) => {},
+///     other => panic!("expected InvalidLink, got {:?}", other),
+/// }
+/// # }
+/// ```
+///
+/// Short links are followed by the [`Api`](Api), which can then fetch the object
+/// with [`Api::resolve`]:
+///
+/// ```rust
+/// # extern crate deezer_metadata;
+/// # use deezer_metadata::api::Api;
+/// # use deezer_metadata::api::objects::link::DeezerRef;
+/// # use deezer_metadata::api::transport::MemoryTransport;
+/// # fn main() {
+/// # let transport = MemoryTransport::new()
+/// #     .with_redirect(
+/// #         "https://deezer.page.link/vb5JMhTXaBX1vWKy6",
+/// #         "https://www.deezer.com/en/playlist/908622995?utm_source=deezer",
+/// #     );
+/// # let deezer = Api::with_transport(transport);
+/// let reference = deezer.parse_link("https://deezer.page.link/vb5JMhTXaBX1vWKy6").unwrap();
+/// assert_eq!(reference, DeezerRef::Playlist(908622995));
+/// # }
+/// ```
+///
+/// [`Api::parse_link`]: ../../struct.Api.html#method.parse_link
+/// [`Api::resolve`]: ../../struct.Api.html#method.resolve
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DeezerRef {
+
+    /// The track with the given id
+    Track(u32),
+
+    /// The album with the given id
+    Album(u32),
+
+    /// The artist with the given id
+    Artist(u32),
+
+    /// The playlist with the given id
+    Playlist(u64),
+
+    /// The user with the given id
+    User(u32),
+
+    /// The radio with the given id
+    Radio(u32),
+
+    /// The podcast with the given id
+    Podcast(u32),
+
+    /// The podcast episode with the given id
+    Episode(u32),
+}
+
+impl DeezerRef {
+
+    /// Returns the Deezer id of the object.
+    pub fn id(&self) -> u64 {
+        match *self {
+            DeezerRef::Playlist(id) => id,
+            DeezerRef::Track(id)
+            | DeezerRef::Album(id)
+            | DeezerRef::Artist(id)
+            | DeezerRef::User(id)
+            | DeezerRef::Radio(id)
+            | DeezerRef::Podcast(id)
+            | DeezerRef::Episode(id) => id.into(),
+        }
+    }
+
+    /// Returns the type of the object, as named by Deezer.
+    pub fn object_type(&self) -> &'static str {
+        match *self {
+            DeezerRef::Track(_) => "track",
+            DeezerRef::Album(_) => "album",
+            DeezerRef::Artist(_) => "artist",
+            DeezerRef::Playlist(_) => "playlist",
+            DeezerRef::User(_) => "user",
+            DeezerRef::Radio(_) => "radio",
+            DeezerRef::Podcast(_) => "podcast",
+            DeezerRef::Episode(_) => "episode",
+        }
+    }
+
+    /// Returns the reference matching a path segment (e.g. `album`) and an id,
+    /// or `None` if the segment is unknown or the id doesn't fit its type.
+    fn from_segment(segment: &str, id: u64) -> Option<Self> {
+        let small = |variant: fn(u32) -> Self| u32::try_from(id).ok().map(variant);
+
+        match segment {
+            "track" => small(DeezerRef::Track),
+            "album" => small(DeezerRef::Album),
+            "artist" => small(DeezerRef::Artist),
+            "playlist" => Some(DeezerRef::Playlist(id)),
+            "user" | "profile" => small(DeezerRef::User),
+            "radio" => small(DeezerRef::Radio),
+            "podcast" | "show" => small(DeezerRef::Podcast),
+            "episode" => small(DeezerRef::Episode),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for DeezerRef {
+
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}/{}", self.object_type(), self.id())
+    }
+}
+
+impl FromStr for DeezerRef {
+
+    type Err = DeezerError;
+
+    fn from_str(link: &str) -> Result<Self, Self::Err> {
+
+        let invalid = || DeezerError::InvalidLink(link.to_owned());
+
+        let url = Url::parse(link.trim()).map_err(|_| invalid())?;
+        let host = url.host_str().unwrap_or("");
+
+        let mut segments = Vec::new();
+
+        match url.scheme() {
+
+            // Both `deezer://www.deezer.com/track/3135556` and `deezer://track/3135556` are used
+            "deezer" => if !is_deezer_host(host) {
+                segments.push(host);
+            },
+            "http" | "https" if is_deezer_host(host) && !is_short_link_host(host) => {},
+            _ => return Err(invalid()),
+        }
+
+        segments.extend(url.path_segments().into_iter().flatten().filter(|s| !s.is_empty()));
+
+        // Skips the language (`/fr/album/302127`) and ignores trailing segments (`/artist/27/top_track`)
+        for pair in segments.windows(2) {
+            let (segment, id) = (pair[0], pair[1]);
+
+            if Self::from_segment(segment, 0).is_none() {
+                continue;
+            }
+
+            let out_of_range = || DeezerError::IdOutOfRange { link: link.to_owned(), id: id.to_owned() };
+
+            match id.parse() {
+                Ok(id) => return Self::from_segment(segment, id).ok_or_else(out_of_range),
+                Err(_) if id.bytes().all(|b| b.is_ascii_digit()) => return Err(out_of_range()),
+                Err(_) => {},
+            }
+        }
+
+        Err(invalid())
+    }
+}
+
+/// Any object a [`DeezerRef`](DeezerRef) can point to.
+#[derive(Debug)]
+pub enum DeezerObject {
+
+    /// A full track
+    Track(Track),
+
+    /// A full album
+    Album(Album),
+
+    /// A full artist
+    Artist(Artist),
+
+    /// A full playlist
+    Playlist(Playlist),
+
+    /// A full user
+    User(User),
+
+    /// A full radio
+    Radio(Radio),
+
+    /// A full podcast
+    Podcast(Podcast),
+
+    /// A full podcast episode
+    Episode(Episode),
+}
+
+fn is_deezer_host(host: &str) -> bool {
+    host == "deezer.com" || host.ends_with(".deezer.com")
+}
+
+fn is_short_link_host(host: &str) -> bool {
+    host == "deezer.page.link" || host == "link.deezer.com"
+}
+
+/// Whether the link is a short link, which only tells what it points to once followed.
+pub(crate) fn is_short_link(link: &str) -> bool {
+    Url::parse(link.trim())
+        .ok()
+        .and_then(|url| url.host_str().map(is_short_link_host))
+        .unwrap_or(false)
+}
@@ -5,34 +5,80 @@ pub mod playlist;
 pub mod chart;
 pub mod comment;
 pub mod editorial;
+pub mod episode;
 pub mod genre;
 pub mod info;
+pub mod link;
 pub mod options;
+pub mod page;
+pub mod podcast;
 pub mod radio;
 pub mod search;
 pub mod user;
 
 
-use serde_json;
+use serde::de::DeserializeOwned;
 use serde_json::Value;
 use serde::{
     Deserialize,
     Deserializer,
 };
 
-// TODO: Handle errors
-pub(crate) fn deserialize_map<'der, T, D>(de: D) -> Result<Vec<T>, D::Error>
-    where D: Deserializer<'der>, for<'de> T: Deserialize<'de>
+use api::errors::{
+    ApiError,
+    DeezerError,
+};
+
+/// Wrapper Deezer uses around every list: `{"data": [...]}`.
+#[derive(Deserialize)]
+struct Data<T> {
+    data: Vec<T>,
+}
+
+pub(crate) fn deserialize_map<'de, T, D>(de: D) -> Result<Vec<T>, D::Error>
+    where D: Deserializer<'de>, T: Deserialize<'de>
+{
+    Data::deserialize(de).map(|helper| helper.data)
+}
+
+/// Decodes a response body into `T`, keeping track of where decoding failed.
+///
+/// Deezer answers with `{"error": {...}}` instead of the requested object when
+/// something goes wrong, so that envelope is checked for first.
+pub(crate) fn from_json<T>(json: &str) -> Result<T, DeezerError>
+    where T: DeserializeOwned
 {
-    let helper: Value = Deserialize::deserialize(de)?;
-    let mut return_value = Vec::<T>::new();
-
-    for object in helper.get("data").unwrap().as_array().unwrap() {
-        match serde_json::from_value(object.clone()) {
-            Ok(value) => return_value.push(value),
-            Err(e) => println!("{}", e)
-        }
+    let value: Value = serde_json::from_str(json)
+        .map_err(|e| DeezerError::Json { path: String::new(), source: e })?;
+
+    if let Some(error) = value.get("error") {
+        let error: ApiError = serde_path_to_error::deserialize(error)
+            .map_err(|e| DeezerError::Json {
+                path: format!("error.{}", e.path()),
+                source: e.into_inner(),
+            })?;
+
+        return Err(error.into());
+    }
+
+    serde_path_to_error::deserialize(value).map_err(|e| DeezerError::Json {
+        path: e.path().to_string(),
+        source: e.into_inner(),
+    })
+}
+
+/// Decodes the `true` Deezer answers write requests with.
+///
+/// A `false` means Deezer didn't apply the change without saying why.
+pub(crate) fn from_json_ack(json: &str) -> Result<(), DeezerError> {
+
+    if from_json::<bool>(json)? {
+        return Ok(());
     }
 
-    Ok(return_value)
+    Err(DeezerError::Api(ApiError {
+        error_type: "Exception".to_owned(),
+        message: "the request was not applied".to_owned(),
+        code: 0,
+    }))
 }
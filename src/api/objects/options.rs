@@ -1,6 +1,13 @@
 //! Contains the [`Options`](Options) struct.
-#[deny(warnings, missing_docs)]
-#[allow(dead_code)]
+#![deny(warnings, missing_docs)]
+#![allow(dead_code)]
+
+use api::{
+    Api,
+    DEFAULT_BASE_URL,
+};
+use api::errors::DeezerError;
+use api::objects::from_json;
 
 /// Contains all the information provided for a user's Options.
 ///
@@ -12,7 +19,7 @@
 /// # extern crate deezer_metadata;
 /// # use deezer_metadata::api::objects::options::Options;
 /// # fn main() {
-/// let options = Options::get();
+/// let options = Options::get().unwrap();
 /// # }
 /// ```
 ///
@@ -29,9 +36,9 @@
 /// let deezer = Api::new();
 ///
 /// // Get as much metadata as you want with the same Api Client
-/// let options = deezer.get_options();
-/// let artist = deezer.get_artist(27);
-/// let track = deezer.get_track(912486);
+/// let options = deezer.get_options().unwrap();
+/// let artist = deezer.get_artist(27).unwrap();
+/// let track = deezer.get_track(912486).unwrap();
 /// # assert_eq!(artist.id, 27);
 /// # assert_eq!(track.id, 912486);
 /// # }
@@ -80,31 +87,31 @@ pub struct Options {
 
 impl Options {
 
-    pub fn new(json: &str) -> Self {
-        serde_json::from_str(&json).unwrap()
+    /// Decodes the `Options` from the JSON of an `options` response.
+    pub fn new(json: &str) -> Result<Self, DeezerError> {
+        from_json(json)
     }
 
     /// Returns an `Options`.
     ///
-    /// Doesn't use [`Api`](Api), better suited for single uses.
+    /// Creates a new [`Api`](Api) on every call, better suited for single uses.
     ///
     /// If you need to make a lot of requests, use [`Api`](Api).
-    pub fn get() -> Self {
-
-        // Get the options api
-        let options_api = get_options_api();
-
-        // Get the json for the options
-        let mut resp = reqwest::get(&options_api).unwrap();
-        let json = resp.text().unwrap();
-
-        Self::new(&json)
+    pub fn get() -> Result<Self, DeezerError> {
+        Api::new().get_options()
     }
 }
 
-/// Takes an id and produces the appropriate api url.
+/// Produces the appropriate api url on [`DEFAULT_BASE_URL`](DEFAULT_BASE_URL).
 pub fn get_options_api() -> String {
 
     // Construct the api url
-    "https://api.deezer.com/options".to_owned()
-}
\ No newline at end of file
+    format!("{}/{}", DEFAULT_BASE_URL, get_options_path())
+}
+
+/// Produces the appropriate api path.
+pub(crate) fn get_options_path() -> String {
+
+    // Construct the api path
+    "options".to_owned()
+}
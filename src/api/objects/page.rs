@@ -0,0 +1,213 @@
+//! Contains the [`Page`](Page) struct.
+//! Also contains the [`Paginated`](Paginated) iterator walking every page of a list.
+#![deny(warnings, missing_docs)]
+#![allow(dead_code)]
+
+use std::vec;
+
+use serde::de::DeserializeOwned;
+
+use api::Api;
+use api::errors::DeezerError;
+use api::transport::Transport;
+use api::objects::from_json;
+
+/// One page of a list returned by Deezer.
+///
+/// Keeps the paging information Deezer sends along with the objects, so the
+/// rest of the list can be fetched.
+#[derive(Deserialize, Serialize, Debug)]
+pub struct Page<T> {
+
+    /// The objects in this page
+    pub data: Vec<T>,
+
+    /// The total number of objects in the list, across every page
+    #[serde(default)]
+    pub total: Option<u32>,
+
+    /// API Link to the previous page, if any
+    #[serde(default)]
+    pub prev: Option<String>,
+
+    /// API Link to the next page, if any
+    #[serde(default)]
+    pub next: Option<String>,
+}
+
+impl<T: DeserializeOwned> Page<T> {
+
+    pub(crate) fn new(json: &str) -> Result<Self, DeezerError> {
+        from_json(json)
+    }
+}
+
+/// Lazily iterates over every object of a list, fetching the next page
+/// whenever the current one runs out.
+///
+/// Returned by [`Api::paginate`] and the `Api` methods fetching lists.
+/// Iteration stops after the first error.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate deezer_metadata;
+/// # use deezer_metadata::api::Api;
+/// # use deezer_metadata::api::transport::MemoryTransport;
+/// # fn main() {
+/// # let genre = |id: u32| format!(r#"{{
+/// #     "id": {}, "name": "", "picture": "", "picture_small": "",
+/// #     "picture_medium": "", "picture_big": "", "picture_xl": ""
+/// # }}"#, id);
+/// # let transport = MemoryTransport::new()
+/// #     .with_body("https://api.deezer.com/genre?index=0&limit=2", &format!(
+/// #         r#"{{"data": [{}, {}], "total": 3, "next": "https://api.deezer.com/genre?index=2&limit=2"}}"#,
+/// #         genre(0), genre(132),
+/// #     ))
+/// #     .with_body("https://api.deezer.com/genre?index=2&limit=2", &format!(
+/// #         r#"{{"data": [{}], "total": 3, "prev": "https://api.deezer.com/genre?index=0&limit=2"}}"#,
+/// #         genre(116),
+/// #     ));
+/// # let deezer = Api::with_transport(transport);
+/// use deezer_metadata::api::objects::genre::Genre;
+///
+/// // Fetches two genres at a time, until there are no more pages
+/// let genres = deezer.paginate::<Genre>("genre")
+///     .index(0)
+///     .limit(2)
+///     .collect::<Result<Vec<_>, _>>()
+///     .unwrap();
+///
+/// # assert_eq!(genres.iter().map(|g| g.id).collect::<Vec<_>>(), vec![0, 132, 116]);
+/// # }
+/// ```
+///
+/// [`Api::paginate`]: ../../struct.Api.html#method.paginate
+pub struct Paginated<'a, T: 'a, U> {
+    api: &'a Api<T>,
+    next: Option<String>,
+    params: Vec<(&'static str, u32)>,
+    items: vec::IntoIter<U>,
+}
+
+impl<'a, T: Transport, U: DeserializeOwned> Paginated<'a, T, U> {
+
+    pub(crate) fn new(api: &'a Api<T>, next: Option<String>, items: Vec<U>) -> Self {
+
+        Paginated {
+            api,
+            next,
+            params: Vec::new(),
+            items: items.into_iter(),
+        }
+    }
+
+    /// Starts at the object with the given index instead of the first one.
+    ///
+    /// Only applies to the first page fetched, call it before iterating.
+    /// Replaces the index of a `next` link given to [`Api::paginate_from`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # extern crate deezer_metadata;
+    /// # use deezer_metadata::api::Api;
+    /// # use deezer_metadata::api::objects::page::Page;
+    /// # use deezer_metadata::api::transport::MemoryTransport;
+    /// # fn main() {
+    /// # let transport = MemoryTransport::new()
+    /// #     .with_body("https://api.deezer.com/genre?limit=2&index=5", r#"{"data": [0, 132]}"#);
+    /// # let deezer = Api::with_transport(transport);
+    /// let page = Page {
+    ///     data: Vec::<u32>::new(),
+    ///     total: Some(7),
+    ///     prev: None,
+    ///     next: Some("https://api.deezer.com/genre?index=2&limit=2".to_owned()),
+    /// };
+    ///
+    /// // Requests `genre?limit=2&index=5`, not `genre?index=2&limit=2&index=5`
+    /// let ids = deezer.paginate_from(page)
+    ///     .index(5)
+    ///     .collect::<Result<Vec<_>, _>>()
+    ///     .unwrap();
+    ///
+    /// # assert_eq!(ids, vec![0, 132]);
+    /// # }
+    /// ```
+    ///
+    /// [`Api::paginate_from`]: ../../struct.Api.html#method.paginate_from
+    pub fn index(mut self, index: u32) -> Self {
+        self.params.push(("index", index));
+        self
+    }
+
+    /// Fetches pages of (at most) the given number of objects.
+    ///
+    /// Only applies to the first page fetched, Deezer keeps it in the `next` links.
+    /// Replaces the limit of a `next` link given to [`Api::paginate_from`].
+    ///
+    /// [`Api::paginate_from`]: ../../struct.Api.html#method.paginate_from
+    pub fn limit(mut self, limit: u32) -> Self {
+        self.params.push(("limit", limit));
+        self
+    }
+
+    /// Fetches the next page, returning `Ok(false)` when there are none left.
+    fn fetch_next_page(&mut self) -> Result<bool, DeezerError> {
+
+        let mut url = match self.next.take() {
+            Some(url) => url,
+            None => return Ok(false),
+        };
+
+        for (name, value) in self.params.drain(..) {
+            url = replace_param(&url, name, value);
+        }
+
+        let page: Page<U> = self.api.fetch_url(&url, Page::new)?;
+
+        self.next = page.next.map(|next| self.api.rebase_url(&next));
+        self.items = page.data.into_iter();
+
+        Ok(true)
+    }
+}
+
+impl<'a, T: Transport, U: DeserializeOwned> Iterator for Paginated<'a, T, U> {
+
+    type Item = Result<U, DeezerError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+
+        loop {
+            if let Some(item) = self.items.next() {
+                return Some(Ok(item));
+            }
+
+            match self.fetch_next_page() {
+                Ok(true) => continue,
+                Ok(false) => return None,
+                Err(e) => return Some(Err(e)),
+            }
+        }
+    }
+}
+
+/// Sets the query parameter of the url to the value, dropping the ones it already had
+/// (`next` links come with their own `index` and `limit`).
+fn replace_param(url: &str, name: &str, value: u32) -> String {
+
+    let (path, query) = match url.find('?') {
+        Some(at) => (&url[..at], &url[at + 1..]),
+        None => (url, ""),
+    };
+
+    let mut pairs: Vec<String> = query.split('&')
+        .filter(|pair| !pair.is_empty() && pair.split('=').next() != Some(name))
+        .map(str::to_owned)
+        .collect();
+
+    pairs.push(format!("{}={}", name, value));
+
+    format!("{}?{}", path, pairs.join("&"))
+}
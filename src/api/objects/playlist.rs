@@ -1,13 +1,20 @@
 //! Contains the [`Playlist`](Playlist) struct.
 //! Also contains a few other helper structs.
-#[deny(warnings, missing_docs)]
-#[allow(dead_code)]
+#![deny(warnings, missing_docs)]
+#![allow(dead_code)]
+
+use api::Api;
+use api::errors::DeezerError;
+use api::transport::Transport;
+use api::objects::{
+    deserialize_map,
+    from_json,
+};
 
 use api::objects::user::User;
 use api::objects::album::Album;
 use api::objects::artist::Artist;
 use api::objects::track::Track;
-use api::objects::deserialize_map;
 
 /// Contains all the information provided for an Album.
 ///
@@ -20,7 +27,7 @@ use api::objects::deserialize_map;
 /// # use deezer_metadata::api::objects::playlist::Playlist;
 /// # fn main() {
 /// // Pass the playlist id into the 'get' method
-/// let playlist = Playlist::get(908622995);
+/// let playlist = Playlist::get(908622995).unwrap();
 /// # assert_eq!(playlist.id, 908622995);
 /// # }
 /// ```
@@ -36,9 +43,9 @@ use api::objects::deserialize_map;
 /// let deezer = Api::new();
 ///
 /// // Get as many albums as you want with the same Api Client
-/// let album1 = deezer.get_playlist(908622995);
-/// let album2 = deezer.get_playlist(1924111242);
-/// let album3 = deezer.get_playlist(754725481);
+/// let album1 = deezer.get_playlist(908622995).unwrap();
+/// let album2 = deezer.get_playlist(1924111242).unwrap();
+/// let album3 = deezer.get_playlist(754725481).unwrap();
 /// # assert_eq!(album1.id, 908622995);
 /// # assert_eq!(album2.id, 1924111242);
 /// # assert_eq!(album3.id, 754725481);
@@ -49,7 +56,7 @@ use api::objects::deserialize_map;
 pub struct Playlist {
 
     /// The playlist's Deezer id
-    pub id: u32,
+    pub id: u64,
 
     /// The playlist's title
     pub title: String,
@@ -121,25 +128,17 @@ pub struct Playlist {
 
 impl Playlist {
 
-    pub(crate) fn new(json: &str) -> Self {
-        serde_json::from_str(&json).unwrap()
+    pub(crate) fn new(json: &str) -> Result<Self, DeezerError> {
+        from_json(json)
     }
 
     /// Returns a `Playlist` from a playlist id.
     ///
-    /// Doesn't use [`Api`](Api), better suited for single uses.
+    /// Creates a new [`Api`](Api) on every call, better suited for single uses.
     ///
     /// If you need to make a lot of requests, use [`Api`](Api).
-    pub fn get(id: u32) -> Self {
-
-        // Get the track api
-        let playlist_api = get_playlist_api(id);
-
-        // Get the json for the track
-        let mut resp = reqwest::get(&playlist_api).unwrap();
-        let json = resp.text().unwrap();
-
-        Self::new(&json)
+    pub fn get(id: u64) -> Result<Self, DeezerError> {
+        Api::new().get_playlist(id)
     }
 }
 
@@ -160,10 +159,16 @@ pub struct PlaylistUser {
 
 impl PlaylistUser {
 
-    /// Returns the corresponding [`User`](User) with all the information available.
-    pub fn get_full(&self) -> User {
+    /// Returns the corresponding [`User`](User) with all the information available,
+    /// through a new uncached [`Api`](Api).
+    pub fn get_full(&self) -> Result<User, DeezerError> {
         User::get(self.id)
     }
+
+    /// Returns the corresponding [`User`](User) through the given [`Api`](Api) and its cache.
+    pub fn get_full_with<T: Transport>(&self, api: &Api<T>) -> Result<User, DeezerError> {
+        api.get_user(self.id)
+    }
 }
 
 /// Shortened version of [`Track`].
@@ -224,10 +229,16 @@ pub struct PlaylistTrack {
 
 impl PlaylistTrack {
 
-    /// Returns the corresponding [`Track`](Track) with all the information available.
-    pub fn get_full(&self) -> Track {
+    /// Returns the corresponding [`Track`](Track) with all the information available,
+    /// through a new uncached [`Api`](Api).
+    pub fn get_full(&self) -> Result<Track, DeezerError> {
         Track::get(self.id)
     }
+
+    /// Returns the corresponding [`Track`](Track) through the given [`Api`](Api) and its cache.
+    pub fn get_full_with<T: Transport>(&self, api: &Api<T>) -> Result<Track, DeezerError> {
+        api.get_track(self.id)
+    }
 }
 
 /// Shortened version of [`Artist`].
@@ -250,10 +261,16 @@ pub struct PlaylistTrackArtist {
 
 impl PlaylistTrackArtist {
 
-    /// Returns the corresponding [`Artist`](Artist) with all the information available.
-    pub fn get_full(&self) -> Artist {
+    /// Returns the corresponding [`Artist`](Artist) with all the information available,
+    /// through a new uncached [`Api`](Api).
+    pub fn get_full(&self) -> Result<Artist, DeezerError> {
         Artist::get(self.id)
     }
+
+    /// Returns the corresponding [`Artist`](Artist) through the given [`Api`](Api) and its cache.
+    pub fn get_full_with<T: Transport>(&self, api: &Api<T>) -> Result<Artist, DeezerError> {
+        api.get_artist(self.id)
+    }
 }
 
 /// Shortened version of [`Album`].
@@ -288,15 +305,127 @@ pub struct PlaylistTrackAlbum {
 
 impl PlaylistTrackAlbum {
 
-    /// Returns the corresponding [`Album`](Album) with all the information available.
-    pub fn get_full(&self) -> Album {
+    /// Returns the corresponding [`Album`](Album) with all the information available,
+    /// through a new uncached [`Api`](Api).
+    pub fn get_full(&self) -> Result<Album, DeezerError> {
         Album::get(self.id)
     }
+
+    /// Returns the corresponding [`Album`](Album) through the given [`Api`](Api) and its cache.
+    pub fn get_full_with<T: Transport>(&self, api: &Api<T>) -> Result<Album, DeezerError> {
+        api.get_album(self.id)
+    }
+}
+
+/// The changes to make to a playlist with [`Api::update_playlist`].
+///
+/// Only the fields that were set are sent to Deezer.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate deezer_metadata;
+/// # use deezer_metadata::api::Api;
+/// # use deezer_metadata::api::objects::playlist::PlaylistUpdate;
+/// # use deezer_metadata::api::transport::{Method, MemoryTransport, Response};
+/// # fn main() {
+/// # let ok = |body: &str| Response::new(200, body);
+/// # let transport = MemoryTransport::new()
+/// #     .with_response(Method::Post, "https://api.deezer.com/user/me/playlists?title=Road+trip&access_token=t0k3n", ok(r#"{"id": 13133771063}"#))
+/// #     .with_response(Method::Post, "https://api.deezer.com/playlist/13133771063/tracks?songs=3135556%2C912486&access_token=t0k3n", ok("true"))
+/// #     .with_response(Method::Post, "https://api.deezer.com/playlist/13133771063?description=Songs+for+the+road&public=false&access_token=t0k3n", ok("true"));
+/// # let deezer = Api::with_transport(transport).with_access_token("t0k3n");
+/// // Needs an access token with the `manage_library` permission
+/// let id = deezer.create_playlist("Road trip").unwrap();
+///
+/// // Recent playlists have ids past `u32::MAX`
+/// assert_eq!(id, 13133771063);
+///
+/// deezer.add_playlist_tracks(id, &[3135556, 912486]).unwrap();
+///
+/// let update = PlaylistUpdate::new()
+///     .description("Songs for the road")
+///     .public(false);
+///
+/// deezer.update_playlist(id, &update).unwrap();
+/// # }
+/// ```
+///
+/// [`Api::update_playlist`]: ../../struct.Api.html#method.update_playlist
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PlaylistUpdate {
+    title: Option<String>,
+    description: Option<String>,
+    public: Option<bool>,
+}
+
+impl PlaylistUpdate {
+
+    /// Returns a `PlaylistUpdate` without any changes.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Renames the playlist.
+    pub fn title(mut self, title: &str) -> Self {
+        self.title = Some(title.to_owned());
+        self
+    }
+
+    /// Sets the description of the playlist.
+    pub fn description(mut self, description: &str) -> Self {
+        self.description = Some(description.to_owned());
+        self
+    }
+
+    /// Makes the playlist public when `true`, private otherwise.
+    pub fn public(mut self, public: bool) -> Self {
+        self.public = Some(public);
+        self
+    }
+
+    /// Returns the parameters to send, in a stable order.
+    pub(crate) fn to_params(&self) -> Vec<(&'static str, String)> {
+
+        let mut params = Vec::new();
+
+        if let Some(ref title) = self.title {
+            params.push(("title", title.clone()));
+        }
+
+        if let Some(ref description) = self.description {
+            params.push(("description", description.clone()));
+        }
+
+        if let Some(public) = self.public {
+            params.push(("public", public.to_string()));
+        }
+
+        params
+    }
+}
+
+/// The answer to the creation of a playlist.
+#[derive(Deserialize)]
+pub(crate) struct CreatedPlaylist {
+    pub(crate) id: u64,
+}
+
+/// Joins track ids into the comma separated list Deezer expects in `songs` and `order`.
+pub(crate) fn join_ids(ids: &[u32]) -> String {
+    ids.iter().map(u32::to_string).collect::<Vec<_>>().join(",")
+}
+
+/// Takes an id and produces the appropriate api path.
+pub(crate) fn get_playlist_path(id: u64) -> String {
+
+    // Construct the api path with the given id
+    "playlist/".to_owned() + &id.to_string()
 }
 
-/// Takes an id and produces the appropriate api url.
-pub(crate) fn get_playlist_api(id: u32) -> String {
+/// Takes an id and a relation (`tracks`, `comments`, ...) and produces the appropriate api path.
+pub(crate) fn get_playlist_relation_path(id: u64, relation: &str) -> String {
 
-    // Construct the api url with the given id
-    "https://api.deezer.com/playlist/".to_owned() + &id.to_string()
+    // Construct the api path with the given id
+    format!("playlist/{}/{}", id, relation)
 }
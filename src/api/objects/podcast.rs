@@ -0,0 +1,180 @@
+//! Contains the [`Podcast`](Podcast) struct.
+//! Also contains the [`PodcastEpisode`](PodcastEpisode) helper struct.
+#![deny(warnings, missing_docs)]
+#![allow(dead_code)]
+
+use api::Api;
+use api::errors::DeezerError;
+use api::transport::Transport;
+use api::objects::from_json;
+use api::objects::episode::Episode;
+
+/// Contains all the information provided for a Podcast.
+///
+/// # Examples
+///
+/// For single uses, you can get a podcast using the `Podcast` struct:
+///
+/// ```rust
+/// # extern crate deezer_metadata;
+/// # use deezer_metadata::api::objects::podcast::Podcast;
+/// # fn main() {
+/// // Pass the podcast id into the 'get' method
+/// let podcast = Podcast::get(1241).unwrap();
+/// # assert_eq!(podcast.id, 1241);
+/// # }
+/// ```
+///
+/// Or, you can use the [`Api`](Api) struct for multiple requests:
+///
+/// ```rust
+/// # extern crate deezer_metadata;
+/// # use deezer_metadata::api::Api;
+/// # use deezer_metadata::api::objects::podcast::Podcast;
+/// # fn main() {
+/// // Get a new Api Client
+/// let deezer = Api::new();
+///
+/// // Get as many podcasts as you want with the same Api Client
+/// let podcast = deezer.get_podcast(1241).unwrap();
+///
+/// // And walk through its episodes, page by page
+/// let episodes = deezer.get_podcast_episodes(podcast.id)
+///     .take(10)
+///     .collect::<Result<Vec<_>, _>>()
+///     .unwrap();
+/// # assert_eq!(podcast.id, 1241);
+/// # assert!(!episodes.is_empty());
+/// # }
+///
+/// ```
+#[derive(Deserialize, Serialize, Debug)]
+pub struct Podcast {
+
+    /// The podcast's Deezer id
+    pub id: u32,
+
+    /// The podcast's title
+    pub title: String,
+
+    /// The podcast's description
+    pub description: String,
+
+    /// If the podcast is available or not
+    pub available: bool,
+
+    /// The number of podcast's fans
+    pub fans: u32,
+
+    /// The url of the podcast on Deezer
+    pub link: String,
+
+    /// The share link of the podcast on Deezer
+    #[serde(rename = "share")]
+    pub share_link: String,
+
+    /// The url of the podcast's cover
+    pub picture: String,
+
+    /// The url of the podcast's cover in size small
+    pub picture_small: String,
+
+    /// The url of the podcast's cover in size medium
+    pub picture_medium: String,
+
+    /// The url of the podcast's cover in size big
+    pub picture_big: String,
+
+    /// The url of the podcast's cover in size xl
+    pub picture_xl: String,
+}
+
+impl Podcast {
+
+    pub(crate) fn new(json: &str) -> Result<Self, DeezerError> {
+        from_json(json)
+    }
+
+    /// Returns a `Podcast` from a podcast id.
+    ///
+    /// Creates a new [`Api`](Api) on every call, better suited for single uses.
+    ///
+    /// If you need to make a lot of requests, use [`Api`](Api).
+    pub fn get(id: u32) -> Result<Self, DeezerError> {
+        Api::new().get_podcast(id)
+    }
+}
+
+/// Shortened version of [`Episode`].
+/// Use [`.get_full()`] for the corresponding [`Episode`] struct.
+///
+/// [`Episode`]: Episode
+/// [`.get_full()`]: struct.PodcastEpisode.html#method.get_full
+#[derive(Deserialize, Serialize, Debug)]
+pub struct PodcastEpisode {
+
+    /// The episode's Deezer id
+    pub id: u32,
+
+    /// The episode's title
+    pub title: String,
+
+    /// The episode's description
+    pub description: String,
+
+    /// If the episode is available or not
+    pub available: bool,
+
+    /// The episode's release date
+    pub release_date: String,
+
+    /// The episode's duration in seconds
+    #[serde(rename = "duration")]
+    pub duration_in_seconds: u32,
+
+    /// The url of the episode on Deezer
+    pub link: String,
+
+    /// The url of the episode's cover
+    pub picture: String,
+
+    /// The url of the episode's cover in size small
+    pub picture_small: String,
+
+    /// The url of the episode's cover in size medium
+    pub picture_medium: String,
+
+    /// The url of the episode's cover in size big
+    pub picture_big: String,
+
+    /// The url of the episode's cover in size xl
+    pub picture_xl: String,
+}
+
+impl PodcastEpisode {
+
+    /// Returns the corresponding [`Episode`](Episode) with all the information available,
+    /// through a new uncached [`Api`](Api).
+    pub fn get_full(&self) -> Result<Episode, DeezerError> {
+        Episode::get(self.id)
+    }
+
+    /// Returns the corresponding [`Episode`](Episode) through the given [`Api`](Api) and its cache.
+    pub fn get_full_with<T: Transport>(&self, api: &Api<T>) -> Result<Episode, DeezerError> {
+        api.get_episode(self.id)
+    }
+}
+
+/// Takes an id and produces the appropriate api path.
+pub(crate) fn get_podcast_path(id: u32) -> String {
+
+    // Construct the api path with the given id
+    "podcast/".to_owned() + &id.to_string()
+}
+
+/// Takes an id and a relation (`episodes`) and produces the appropriate api path.
+pub(crate) fn get_podcast_relation_path(id: u32, relation: &str) -> String {
+
+    // Construct the api path with the given id
+    format!("podcast/{}/{}", id, relation)
+}
@@ -1,6 +1,17 @@
 //! Contains the [`Radio`](Radio) struct.
-#[deny(warnings, missing_docs)]
-#[allow(dead_code)]
+//! Also contains the [`RadioGenre`](RadioGenre) helper struct.
+//! Radios in lists are [`SearchRadio`](SearchRadio)s.
+#![deny(warnings, missing_docs)]
+#![allow(dead_code)]
+
+use api::{
+    Api,
+    DEFAULT_BASE_URL,
+};
+use api::errors::DeezerError;
+use api::objects::from_json;
+
+pub use api::objects::search::SearchRadio;
 
 /// Contains all the information provided for a Radio.
 ///
@@ -13,7 +24,7 @@
 /// # use deezer_metadata::api::objects::radio::Radio;
 /// # fn main() {
 /// // Pass the radio id into the 'get' method
-/// let radio = Radio::get(6);
+/// let radio = Radio::get(6).unwrap();
 /// # assert_eq!(radio.id, 6);
 /// # }
 /// ```
@@ -29,9 +40,9 @@
 /// let deezer = Api::new();
 ///
 /// // Get as many albums as you want with the same Api Client
-/// let radio1 = deezer.get_radio(6);
-/// let radio2 = deezer.get_radio(7);
-/// let radio3 = deezer.get_radio(10);
+/// let radio1 = deezer.get_radio(6).unwrap();
+/// let radio2 = deezer.get_radio(7).unwrap();
+/// let radio3 = deezer.get_radio(10).unwrap();
 /// # assert_eq!(radio1.id, 6);
 /// # assert_eq!(radio2.id, 7);
 /// # assert_eq!(radio3.id, 10);
@@ -76,31 +87,75 @@ pub struct Radio {
 
 impl Radio {
 
-    pub fn new(json: &str) -> Self {
-        serde_json::from_str(&json).unwrap()
+    /// Decodes a `Radio` from the JSON of a `radio/{id}` response.
+    pub fn new(json: &str) -> Result<Self, DeezerError> {
+        from_json(json)
     }
 
     /// Returns a `Radio` from a radio id.
     ///
-    /// Doesn't use [`Api`](Api), better suited for single uses.
+    /// Creates a new [`Api`](Api) on every call, better suited for single uses.
+    ///
+    /// If you need to make a lot of requests, use [`Api`](Api).
+    pub fn get(id: u32) -> Result<Self, DeezerError> {
+        Api::new().get_radio(id)
+    }
+
+    /// Returns every radio available on Deezer.
+    ///
+    /// Creates a new [`Api`](Api) on every call, better suited for single uses.
     ///
     /// If you need to make a lot of requests, use [`Api`](Api).
-    pub fn get(id: u32) -> Self {
+    pub fn all() -> Result<Vec<SearchRadio>, DeezerError> {
+        Api::new().get_radios()
+    }
+}
 
-        // Get the track api
-        let radio_api = get_radio_api(id);
+/// The radios of a genre.
+#[derive(Deserialize, Serialize, Debug)]
+pub struct RadioGenre {
 
-        // Get the json for the track
-        let mut resp = reqwest::get(&radio_api).unwrap();
-        let json = resp.text().unwrap();
+    /// The genre's Deezer id
+    pub id: u32,
 
-        Self::new(&json)
-    }
+    /// The genre's name
+    pub title: String,
+
+    /// The radios of the genre
+    pub radios: Vec<SearchRadio>,
 }
 
-/// Takes an id and produces the appropriate api url.
+/// Takes an id and produces the appropriate api url on [`DEFAULT_BASE_URL`](DEFAULT_BASE_URL).
 pub fn get_radio_api(id: u32) -> String {
 
     // Construct the api url with the given id
-    "https://api.deezer.com/radio/".to_owned() + &id.to_string()
-}
\ No newline at end of file
+    format!("{}/{}", DEFAULT_BASE_URL, get_radio_path(id))
+}
+
+/// Takes an id and produces the appropriate api path.
+pub(crate) fn get_radio_path(id: u32) -> String {
+
+    // Construct the api path with the given id
+    "radio/".to_owned() + &id.to_string()
+}
+
+/// Produces the appropriate api path for the list of radios.
+pub(crate) fn get_radios_path() -> String {
+
+    // Construct the api path
+    "radio".to_owned()
+}
+
+/// Takes a list (`genres`, `top`, ...) and produces the appropriate api path.
+pub(crate) fn get_radio_list_path(list: &str) -> String {
+
+    // Construct the api path with the given list
+    "radio/".to_owned() + list
+}
+
+/// Takes an id and produces the appropriate api path for the radio's tracks.
+pub(crate) fn get_radio_tracks_path(id: u32) -> String {
+
+    // Construct the api path with the given id
+    format!("radio/{}/tracks", id)
+}
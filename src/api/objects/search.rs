@@ -0,0 +1,542 @@
+//! Contains the structs returned when searching Deezer.
+//! Results whose shape matches an existing shortened struct reuse it:
+//! playlists are [`ChartPlaylist`](ChartPlaylist)s and users are
+//! [`CommentAuthor`](CommentAuthor)s.
+#![deny(warnings, missing_docs)]
+#![allow(dead_code)]
+
+use std::fmt;
+
+use url::form_urlencoded;
+
+use api::Api;
+use api::errors::DeezerError;
+use api::transport::Transport;
+use api::objects::album::{
+    Album,
+    AlbumArtist,
+};
+use api::objects::artist::Artist;
+use api::objects::playlist::{
+    PlaylistTrackAlbum,
+    PlaylistTrackArtist,
+};
+use api::objects::radio::Radio;
+use api::objects::track::Track;
+
+pub use api::objects::chart::ChartPlaylist;
+pub use api::objects::comment::CommentAuthor;
+
+/// A search query, using Deezer's advanced search syntax.
+///
+/// Every `Api::search_*` method accepts either a plain `&str` or a `SearchQuery`.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate deezer_metadata;
+/// # use deezer_metadata::api::objects::search::{SearchOrder, SearchQuery};
+/// # fn main() {
+/// let query = SearchQuery::new()
+///     .artist("Daft Punk")
+///     .track("Harder, Better, Faster, Stronger")
+///     .dur_max(300)
+///     .order(SearchOrder::RatingDesc)
+///     .strict(true);
+///
+/// assert_eq!(
+///     query.to_string(),
+///     r#"artist:"Daft Punk" track:"Harder, Better, Faster, Stronger" dur_max:300"#,
+/// );
+/// assert_eq!(
+///     query.to_query_string(),
+///     "q=artist%3A%22Daft+Punk%22+track%3A%22Harder%2C+Better%2C+Faster%2C+Stronger%22\
+///      +dur_max%3A300&order=RATING_DESC&strict=on",
+/// );
+///
+/// // Quotes in field values are escaped
+/// let query = SearchQuery::new().album(r#"The "Blue" Album"#);
+/// assert_eq!(query.to_string(), r#"album:"The \"Blue\" Album""#);
+/// # }
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SearchQuery {
+    text: Option<String>,
+    artist: Option<String>,
+    album: Option<String>,
+    track: Option<String>,
+    label: Option<String>,
+    dur_min: Option<u32>,
+    dur_max: Option<u32>,
+    bpm_min: Option<u32>,
+    bpm_max: Option<u32>,
+    order: Option<SearchOrder>,
+    strict: bool,
+}
+
+impl SearchQuery {
+
+    /// Returns an empty `SearchQuery`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the free text to search for.
+    pub fn text(mut self, text: &str) -> Self {
+        self.text = Some(text.to_owned());
+        self
+    }
+
+    /// Only matches results by the given artist.
+    pub fn artist(mut self, artist: &str) -> Self {
+        self.artist = Some(artist.to_owned());
+        self
+    }
+
+    /// Only matches results from the given album.
+    pub fn album(mut self, album: &str) -> Self {
+        self.album = Some(album.to_owned());
+        self
+    }
+
+    /// Only matches results with the given track title.
+    pub fn track(mut self, track: &str) -> Self {
+        self.track = Some(track.to_owned());
+        self
+    }
+
+    /// Only matches results released under the given label.
+    pub fn label(mut self, label: &str) -> Self {
+        self.label = Some(label.to_owned());
+        self
+    }
+
+    /// Only matches tracks lasting at least the given number of seconds.
+    pub fn dur_min(mut self, seconds: u32) -> Self {
+        self.dur_min = Some(seconds);
+        self
+    }
+
+    /// Only matches tracks lasting at most the given number of seconds.
+    pub fn dur_max(mut self, seconds: u32) -> Self {
+        self.dur_max = Some(seconds);
+        self
+    }
+
+    /// Only matches tracks with at least the given beats per minute.
+    pub fn bpm_min(mut self, bpm: u32) -> Self {
+        self.bpm_min = Some(bpm);
+        self
+    }
+
+    /// Only matches tracks with at most the given beats per minute.
+    pub fn bpm_max(mut self, bpm: u32) -> Self {
+        self.bpm_max = Some(bpm);
+        self
+    }
+
+    /// Sets the order results are returned in.
+    pub fn order(mut self, order: SearchOrder) -> Self {
+        self.order = Some(order);
+        self
+    }
+
+    /// Disables Deezer's fuzzy matching when `true`.
+    pub fn strict(mut self, strict: bool) -> Self {
+        self.strict = strict;
+        self
+    }
+
+    /// Returns the url encoded query string (`q=...&order=...&strict=on`).
+    pub fn to_query_string(&self) -> String {
+
+        let mut serializer = form_urlencoded::Serializer::new(String::new());
+        serializer.append_pair("q", &self.to_string());
+
+        if let Some(order) = self.order {
+            serializer.append_pair("order", order.as_str());
+        }
+
+        if self.strict {
+            serializer.append_pair("strict", "on");
+        }
+
+        serializer.finish()
+    }
+}
+
+impl fmt::Display for SearchQuery {
+
+    /// Writes the `q` parameter, e.g. `daft punk artist:"Daft Punk" bpm_min:120`.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+
+        let mut terms = Vec::new();
+
+        if let Some(ref text) = self.text {
+            terms.push(text.clone());
+        }
+
+        let fields = [
+            ("artist", &self.artist),
+            ("album", &self.album),
+            ("track", &self.track),
+            ("label", &self.label),
+        ];
+
+        for &(name, value) in fields.iter() {
+            if let Some(ref value) = *value {
+                terms.push(format!("{}:\"{}\"", name, escape(value)));
+            }
+        }
+
+        let numbers = [
+            ("dur_min", self.dur_min),
+            ("dur_max", self.dur_max),
+            ("bpm_min", self.bpm_min),
+            ("bpm_max", self.bpm_max),
+        ];
+
+        for &(name, value) in numbers.iter() {
+            if let Some(value) = value {
+                terms.push(format!("{}:{}", name, value));
+            }
+        }
+
+        write!(f, "{}", terms.join(" "))
+    }
+}
+
+impl<'a> From<&'a str> for SearchQuery {
+
+    fn from(text: &'a str) -> Self {
+        Self::new().text(text)
+    }
+}
+
+impl From<String> for SearchQuery {
+
+    fn from(text: String) -> Self {
+        Self::new().text(&text)
+    }
+}
+
+/// The order search results are returned in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SearchOrder {
+
+    /// `RANKING`
+    Ranking,
+
+    /// `TRACK_ASC`
+    TrackAsc,
+
+    /// `TRACK_DESC`
+    TrackDesc,
+
+    /// `ARTIST_ASC`
+    ArtistAsc,
+
+    /// `ARTIST_DESC`
+    ArtistDesc,
+
+    /// `ALBUM_ASC`
+    AlbumAsc,
+
+    /// `ALBUM_DESC`
+    AlbumDesc,
+
+    /// `RATING_ASC`
+    RatingAsc,
+
+    /// `RATING_DESC`
+    RatingDesc,
+
+    /// `DURATION_ASC`
+    DurationAsc,
+
+    /// `DURATION_DESC`
+    DurationDesc,
+}
+
+impl SearchOrder {
+
+    /// Returns the value Deezer expects in the `order` parameter.
+    pub fn as_str(&self) -> &'static str {
+        match *self {
+            SearchOrder::Ranking => "RANKING",
+            SearchOrder::TrackAsc => "TRACK_ASC",
+            SearchOrder::TrackDesc => "TRACK_DESC",
+            SearchOrder::ArtistAsc => "ARTIST_ASC",
+            SearchOrder::ArtistDesc => "ARTIST_DESC",
+            SearchOrder::AlbumAsc => "ALBUM_ASC",
+            SearchOrder::AlbumDesc => "ALBUM_DESC",
+            SearchOrder::RatingAsc => "RATING_ASC",
+            SearchOrder::RatingDesc => "RATING_DESC",
+            SearchOrder::DurationAsc => "DURATION_ASC",
+            SearchOrder::DurationDesc => "DURATION_DESC",
+        }
+    }
+}
+
+/// Escapes a field value so it can be put between double quotes.
+fn escape(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+/// A track found with [`Api::search_tracks`].
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate deezer_metadata;
+/// # use deezer_metadata::api::Api;
+/// # fn main() {
+/// // Get a new Api Client
+/// let deezer = Api::new();
+///
+/// // Search for tracks
+/// let results = deezer.search_tracks("eminem").unwrap();
+///
+/// for track in &results.data {
+///     println!("{} - {}", track.artist.name, track.title);
+/// }
+/// # assert!(results.total.unwrap() > 0);
+/// # }
+/// ```
+///
+/// [`Api::search_tracks`]: ../../struct.Api.html#method.search_tracks
+#[derive(Deserialize, Serialize, Debug)]
+pub struct SearchTrack {
+
+    /// `The track's Deezer id`
+    pub id: u32,
+
+    /// `True if the track is readable in the player for the current user`
+    pub readable: bool,
+
+    /// `The track's full title`
+    pub title: String,
+
+    /// `The track's short title`
+    pub title_short: String,
+
+    /// `The track's version`
+    #[serde(default)]
+    pub title_version: String,
+
+    /// `The url of the track on Deezer`
+    pub link: String,
+
+    /// `The track's duration in seconds`
+    #[serde(rename = "duration")]
+    pub duration_in_seconds: u32,
+
+    /// `The track's Deezer rank`
+    pub rank: u32,
+
+    /// `Whether the track contains explicit lyrics`
+    #[serde(rename = "explicit_lyrics")]
+    pub has_explicit_lyrics: bool,
+
+    /// `The url of track's preview file. This file contains the first 30 seconds of the track`
+    #[serde(default)]
+    #[serde(rename = "preview")]
+    pub preview_url: Option<String>,
+
+    /// `Artist Object`
+    pub artist: PlaylistTrackArtist,
+
+    /// `Album Object`
+    pub album: PlaylistTrackAlbum,
+}
+
+impl SearchTrack {
+
+    /// Returns the corresponding [`Track`](Track) with all the information available,
+    /// through a new uncached [`Api`](Api).
+    pub fn get_full(&self) -> Result<Track, DeezerError> {
+        Track::get(self.id)
+    }
+
+    /// Returns the corresponding [`Track`](Track) through the given [`Api`](Api) and its cache.
+    pub fn get_full_with<T: Transport>(&self, api: &Api<T>) -> Result<Track, DeezerError> {
+        api.get_track(self.id)
+    }
+}
+
+/// An album found with [`Api::search_albums`].
+///
+/// [`Api::search_albums`]: ../../struct.Api.html#method.search_albums
+#[derive(Deserialize, Serialize, Debug)]
+pub struct SearchAlbum {
+
+    /// `The Deezer album id`
+    pub id: u32,
+
+    /// `The album title`
+    pub title: String,
+
+    /// `The url of the album on Deezer`
+    pub link: String,
+
+    /// `The url of the album's cover.`
+    pub cover: String,
+
+    /// `The url of the album's cover in size small.`
+    pub cover_small: String,
+
+    /// `The url of the album's cover in size medium.`
+    pub cover_medium: String,
+
+    /// `The url of the album's cover in size big.`
+    pub cover_big: String,
+
+    /// `The url of the album's cover in size xl.`
+    pub cover_xl: String,
+
+    /// `The album's first genre id`
+    #[serde(default)]
+    pub genre_id: Option<i32>,
+
+    /// `Number of tracks in the album`
+    pub nb_tracks: u32,
+
+    /// `The record type of the album (EP / ALBUM / etc..)`
+    pub record_type: String,
+
+    /// `API Link to the tracklist of this album`
+    pub tracklist: String,
+
+    /// `Whether the album contains explicit lyrics`
+    #[serde(rename = "explicit_lyrics")]
+    pub has_explicit_lyrics: bool,
+
+    /// `Returns an AlbumArtist object of the artist this album belongs to`
+    pub artist: AlbumArtist,
+}
+
+impl SearchAlbum {
+
+    /// Returns the corresponding [`Album`](Album) with all the information available,
+    /// through a new uncached [`Api`](Api).
+    pub fn get_full(&self) -> Result<Album, DeezerError> {
+        Album::get(self.id)
+    }
+
+    /// Returns the corresponding [`Album`](Album) through the given [`Api`](Api) and its cache.
+    pub fn get_full_with<T: Transport>(&self, api: &Api<T>) -> Result<Album, DeezerError> {
+        api.get_album(self.id)
+    }
+}
+
+/// An artist found with [`Api::search_artists`].
+///
+/// [`Api::search_artists`]: ../../struct.Api.html#method.search_artists
+#[derive(Deserialize, Serialize, Debug)]
+pub struct SearchArtist {
+
+    /// `The artist's Deezer id`
+    pub id: u32,
+
+    /// `The artist's name`
+    pub name: String,
+
+    /// `The url of the artist on Deezer`
+    pub link: String,
+
+    /// `The url of the artist picture.`
+    pub picture: String,
+
+    /// `The url of the artist picture in size small`
+    pub picture_small: String,
+
+    /// `The url of the artist picture in size medium`
+    pub picture_medium: String,
+
+    /// `The url of the artist picture in size big`
+    pub picture_big: String,
+
+    /// `The url of the artist picture in size xl`
+    pub picture_xl: String,
+
+    /// `The number of artist's albums`
+    pub nb_album: u32,
+
+    /// `The number of artist's fans`
+    pub nb_fan: u32,
+
+    /// `True if the artist has a smartradio`
+    #[serde(rename = "radio")]
+    pub has_radio: bool,
+
+    /// `API Link to the top of this artist`
+    pub tracklist: String,
+}
+
+impl SearchArtist {
+
+    /// Returns the corresponding [`Artist`](Artist) with all the information available,
+    /// through a new uncached [`Api`](Api).
+    pub fn get_full(&self) -> Result<Artist, DeezerError> {
+        Artist::get(self.id)
+    }
+
+    /// Returns the corresponding [`Artist`](Artist) through the given [`Api`](Api) and its cache.
+    pub fn get_full_with<T: Transport>(&self, api: &Api<T>) -> Result<Artist, DeezerError> {
+        api.get_artist(self.id)
+    }
+}
+
+/// A radio found with [`Api::search_radios`].
+///
+/// [`Api::search_radios`]: ../../struct.Api.html#method.search_radios
+#[derive(Deserialize, Serialize, Debug)]
+pub struct SearchRadio {
+
+    /// The radio deezer ID
+    pub id: u32,
+
+    /// The radio title
+    pub title: String,
+
+    /// The url of the radio picture
+    pub picture: String,
+
+    /// The url of the radio picture in size small
+    pub picture_small: String,
+
+    /// The url of the radio picture in size medium
+    pub picture_medium: String,
+
+    /// The url of the radio picture in size big
+    pub picture_big: String,
+
+    /// The url of the radio picture in size xl
+    pub picture_xl: String,
+
+    /// API Link to the tracklist of this radio
+    #[serde(rename = "tracklist")]
+    pub track_list: String,
+}
+
+impl SearchRadio {
+
+    /// Returns the corresponding [`Radio`](Radio) with all the information available,
+    /// through a new uncached [`Api`](Api).
+    pub fn get_full(&self) -> Result<Radio, DeezerError> {
+        Radio::get(self.id)
+    }
+
+    /// Returns the corresponding [`Radio`](Radio) through the given [`Api`](Api) and its cache.
+    pub fn get_full_with<T: Transport>(&self, api: &Api<T>) -> Result<Radio, DeezerError> {
+        api.get_radio(self.id)
+    }
+}
+
+/// Takes the kind of object searched for (`track`, `album`, ...) and a query
+/// and produces the appropriate api path.
+pub(crate) fn get_search_path(kind: &str, query: &SearchQuery) -> String {
+
+    // Construct the api path with the given query
+    format!("search/{}?{}", kind, query.to_query_string())
+}
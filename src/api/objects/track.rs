@@ -1,7 +1,15 @@
 //! Contains the [`Track`](Track) struct.
 //! Also contains a few other helper structs.
-#[deny(warnings, missing_docs)]
-#[allow(dead_code)]
+#![deny(warnings, missing_docs)]
+#![allow(dead_code)]
+
+use api::{
+    Api,
+    DEFAULT_BASE_URL,
+};
+use api::errors::DeezerError;
+use api::transport::Transport;
+use api::objects::from_json;
 
 use api::objects::artist::Artist;
 use api::objects::album::Album;
@@ -17,7 +25,7 @@ use api::objects::album::Album;
 /// # use deezer_metadata::api::objects::track::Track;
 /// # fn main() {
 /// // Pass the track id into the 'get' method
-/// let track = Track::get(912486);
+/// let track = Track::get(912486).unwrap();
 /// # assert_eq!(track.id, 912486);
 /// # }
 /// ```
@@ -33,9 +41,9 @@ use api::objects::album::Album;
 /// let deezer = Api::new();
 ///
 /// // Get as many tracks as you want with the same Api Client
-/// let track1 = deezer.get_track(912486);
-/// let track2 = deezer.get_track(912487);
-/// let track3 = deezer.get_track(912488);
+/// let track1 = deezer.get_track(912486).unwrap();
+/// let track2 = deezer.get_track(912487).unwrap();
+/// let track3 = deezer.get_track(912488).unwrap();
 /// # assert_eq!(track1.id, 912486);
 /// # assert_eq!(track2.id, 912487);
 /// # assert_eq!(track3.id, 912488);
@@ -127,25 +135,26 @@ pub struct Track {
 
 impl Track {
 
-    pub(crate) fn new(json: &str) -> Self {
-        serde_json::from_str(&json).unwrap()
+    pub(crate) fn new(json: &str) -> Result<Self, DeezerError> {
+        from_json(json)
     }
 
     /// Returns a `Track` from a track id.
     ///
-    /// Doesn't use [`Api`](Api), better suited for single uses.
+    /// Creates a new [`Api`](Api) on every call, better suited for single uses.
     ///
     /// If you need to make a lot of requests, use [`Api`](Api).
-    pub fn get(id: u32) -> Self {
-
-        // Get the track api
-        let track_api = get_track_api(id);
-
-        // Get the json for the track
-        let mut resp = reqwest::get(&track_api).unwrap();
-        let json = resp.text().unwrap();
+    pub fn get(id: u32) -> Result<Self, DeezerError> {
+        Api::new().get_track(id)
+    }
 
-        Self::new(&json)
+    /// Returns a `Track` from its ISRC (e.g. `GBDUW0000059`).
+    ///
+    /// Creates a new [`Api`](Api) on every call, better suited for single uses.
+    ///
+    /// If you need to make a lot of requests, use [`Api`](Api).
+    pub fn get_by_isrc(isrc: &str) -> Result<Self, DeezerError> {
+        Api::new().get_track_by_isrc(isrc)
     }
 }
 
@@ -192,10 +201,16 @@ pub struct ContributorArtist {
 
 impl ContributorArtist {
 
-    /// Returns the corresponding [`Artist`](Artist) with all the information available.
-    pub fn get_full(&self) -> Artist {
+    /// Returns the corresponding [`Artist`](Artist) with all the information available,
+    /// through a new uncached [`Api`](Api).
+    pub fn get_full(&self) -> Result<Artist, DeezerError> {
         Artist::get(self.id)
     }
+
+    /// Returns the corresponding [`Artist`](Artist) through the given [`Api`](Api) and its cache.
+    pub fn get_full_with<T: Transport>(&self, api: &Api<T>) -> Result<Artist, DeezerError> {
+        api.get_artist(self.id)
+    }
 }
 
 /// Shortened version of [`Artist`].
@@ -252,10 +267,16 @@ pub struct TrackArtist {
 
 impl TrackArtist {
 
-    /// Returns the corresponding [`Artist`](Artist) with all the information available.
-    pub fn get_full(&self) -> Artist {
+    /// Returns the corresponding [`Artist`](Artist) with all the information available,
+    /// through a new uncached [`Api`](Api).
+    pub fn get_full(&self) -> Result<Artist, DeezerError> {
         Artist::get(self.id)
     }
+
+    /// Returns the corresponding [`Artist`](Artist) through the given [`Api`](Api) and its cache.
+    pub fn get_full_with<T: Transport>(&self, api: &Api<T>) -> Result<Artist, DeezerError> {
+        api.get_artist(self.id)
+    }
 }
 
 /// Shortened version of [`Album`].
@@ -296,15 +317,55 @@ pub struct TrackAlbum {
 
 impl TrackAlbum {
 
-    /// Returns the corresponding [`Album`](Album) with all the information available.
-    pub fn get_full(&self) -> Album {
+    /// Returns the corresponding [`Album`](Album) with all the information available,
+    /// through a new uncached [`Api`](Api).
+    pub fn get_full(&self) -> Result<Album, DeezerError> {
         Album::get(self.id)
     }
+
+    /// Returns the corresponding [`Album`](Album) through the given [`Api`](Api) and its cache.
+    pub fn get_full_with<T: Transport>(&self, api: &Api<T>) -> Result<Album, DeezerError> {
+        api.get_album(self.id)
+    }
 }
 
-/// Takes an id and produces the appropriate api url.
+/// Takes an id and produces the appropriate api url on [`DEFAULT_BASE_URL`](DEFAULT_BASE_URL).
 pub fn get_track_api(id: u32) -> String {
 
     // Construct the api url with the given id
-    "https://api.deezer.com/track/".to_owned() + &id.to_string()
-}
\ No newline at end of file
+    format!("{}/{}", DEFAULT_BASE_URL, get_track_path(id))
+}
+
+/// Takes an id and produces the appropriate api path.
+pub(crate) fn get_track_path(id: u32) -> String {
+
+    // Construct the api path with the given id
+    "track/".to_owned() + &id.to_string()
+}
+
+/// Takes an ISRC and produces the appropriate api path.
+///
+/// Dashes and case are ignored (`gb-duw-00-00059` is `GBDUW0000059`), anything
+/// else not shaped like an ISRC is rejected with [`DeezerError::InvalidCode`].
+pub(crate) fn get_track_by_isrc_path(isrc: &str) -> Result<String, DeezerError> {
+
+    let code: String = isrc.trim()
+        .chars()
+        .filter(|c| *c != '-')
+        .map(|c| c.to_ascii_uppercase())
+        .collect();
+
+    // Country (2 letters), registrant (3 alphanumerics), year (2 digits), designation (5 digits)
+    let bytes = code.as_bytes();
+    let is_valid = bytes.len() == 12
+        && bytes[..2].iter().all(u8::is_ascii_alphabetic)
+        && bytes[2..5].iter().all(u8::is_ascii_alphanumeric)
+        && bytes[5..].iter().all(u8::is_ascii_digit);
+
+    if !is_valid {
+        return Err(DeezerError::InvalidCode { kind: "ISRC", code: isrc.to_owned() });
+    }
+
+    // Construct the api path with the given code
+    Ok("track/isrc:".to_owned() + &code)
+}
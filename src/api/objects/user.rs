@@ -1,6 +1,23 @@
 //! Contains the [`User`](User) struct.
-#[deny(warnings, missing_docs)]
-#[allow(dead_code)]
+//! Also contains a few other helper structs.
+#![deny(warnings, missing_docs)]
+#![allow(dead_code)]
+
+use api::Api;
+use api::errors::DeezerError;
+use api::transport::Transport;
+use api::objects::from_json;
+use api::objects::album::{
+    Album,
+    AlbumArtist,
+};
+use api::objects::artist::Artist;
+use api::objects::playlist::{
+    Playlist,
+    PlaylistTrackAlbum,
+    PlaylistUser,
+};
+use api::objects::track::Track;
 
 /// Contains all the information provided for a User.
 ///
@@ -13,7 +30,7 @@
 /// # use deezer_metadata::api::objects::user::User;
 /// # fn main() {
 /// // Pass the user id into the 'get' method
-/// let user = User::get(12);
+/// let user = User::get(12).unwrap();
 /// # assert_eq!(user.id, 12);
 /// # }
 /// ```
@@ -29,9 +46,9 @@
 /// let deezer = Api::new();
 ///
 /// // Get as many albums as you want with the same Api Client
-/// let user1 = deezer.get_user(12);
-/// let user2 = deezer.get_user(13);
-/// let user3 = deezer.get_user(14);
+/// let user1 = deezer.get_user(12).unwrap();
+/// let user2 = deezer.get_user(13).unwrap();
+/// let user3 = deezer.get_user(14).unwrap();
 /// # assert_eq!(user1.id, 12);
 /// # assert_eq!(user2.id, 13);
 /// # assert_eq!(user3.id, 14);
@@ -113,31 +130,379 @@ pub struct User {
 
 impl User {
 
-    pub(crate) fn new(json: &str) -> Self {
-        serde_json::from_str(&json).unwrap()
+    pub(crate) fn new(json: &str) -> Result<Self, DeezerError> {
+        from_json(json)
     }
 
     /// Returns a `User` from a user id.
     ///
-    /// Doesn't use [`Api`](Api), better suited for single uses.
+    /// Creates a new [`Api`](Api) on every call, better suited for single uses.
     ///
     /// If you need to make a lot of requests, use [`Api`](Api).
-    pub fn get(id: u32) -> Self {
+    pub fn get(id: u32) -> Result<Self, DeezerError> {
+        Api::new().get_user(id)
+    }
+}
+
+/// Shortened version of [`Album`].
+/// Use [`.get_full()`] for the corresponding [`Album`] struct.
+///
+/// Returned by the user's favorite albums and charts.
+///
+/// [`Album`]: Album
+/// [`.get_full()`]: struct.UserAlbum.html#method.get_full
+#[derive(Deserialize, Serialize, Debug)]
+pub struct UserAlbum {
+
+    /// `The Deezer album id`
+    pub id: u32,
+
+    /// `The album title`
+    pub title: String,
+
+    /// `The url of the album on Deezer`
+    pub link: String,
+
+    /// `The url of the album's cover.`
+    pub cover: String,
+
+    /// `The url of the album's cover in size small.`
+    pub cover_small: String,
+
+    /// `The url of the album's cover in size medium.`
+    pub cover_medium: String,
+
+    /// `The url of the album's cover in size big.`
+    pub cover_big: String,
+
+    /// `The url of the album's cover in size xl.`
+    pub cover_xl: String,
+
+    /// `Number of tracks in the album`
+    #[serde(default)]
+    pub nb_tracks: Option<u32>,
+
+    /// `The album's release date`
+    #[serde(default)]
+    pub release_date: Option<String>,
+
+    /// `The record type of the album (EP / ALBUM / etc..)`
+    pub record_type: String,
+
+    /// `API Link to the tracklist of this album`
+    pub tracklist: String,
+
+    /// `Whether the album contains explicit lyrics`
+    #[serde(rename = "explicit_lyrics")]
+    pub has_explicit_lyrics: bool,
+
+    /// `The time when the album has been added to the user's favorites`
+    #[serde(default)]
+    #[serde(rename = "time_add")]
+    pub added_on: Option<u64>,
+
+    /// `Returns an AlbumArtist object of the artist this album belongs to`
+    pub artist: AlbumArtist,
+}
+
+impl UserAlbum {
+
+    /// Returns the corresponding [`Album`](Album) with all the information available,
+    /// through a new uncached [`Api`](Api).
+    pub fn get_full(&self) -> Result<Album, DeezerError> {
+        Album::get(self.id)
+    }
+
+    /// Returns the corresponding [`Album`](Album) through the given [`Api`](Api) and its cache.
+    pub fn get_full_with<T: Transport>(&self, api: &Api<T>) -> Result<Album, DeezerError> {
+        api.get_album(self.id)
+    }
+}
+
+/// Shortened version of [`Artist`].
+/// Use [`.get_full()`] for the corresponding [`Artist`] struct.
+///
+/// Returned by the user's favorite artists and charts.
+///
+/// [`Artist`]: Artist
+/// [`.get_full()`]: struct.UserArtist.html#method.get_full
+#[derive(Deserialize, Serialize, Debug)]
+pub struct UserArtist {
+
+    /// `The artist's Deezer id`
+    pub id: u32,
+
+    /// `The artist's name`
+    pub name: String,
+
+    /// `The url of the artist on Deezer`
+    pub link: String,
+
+    /// `The url of the artist picture.`
+    pub picture: String,
+
+    /// `The url of the artist picture in size small`
+    pub picture_small: String,
+
+    /// `The url of the artist picture in size medium`
+    pub picture_medium: String,
+
+    /// `The url of the artist picture in size big`
+    pub picture_big: String,
+
+    /// `The url of the artist picture in size xl`
+    pub picture_xl: String,
+
+    /// `The number of artist's albums`
+    #[serde(default)]
+    pub nb_album: Option<u32>,
+
+    /// `The number of artist's fans`
+    #[serde(default)]
+    pub nb_fan: Option<u32>,
+
+    /// `True if the artist has a smartradio`
+    #[serde(rename = "radio")]
+    pub has_radio: bool,
+
+    /// `API Link to the top of this artist`
+    pub tracklist: String,
+
+    /// `The time when the artist has been added to the user's favorites`
+    #[serde(default)]
+    #[serde(rename = "time_add")]
+    pub added_on: Option<u64>,
+}
+
+impl UserArtist {
+
+    /// Returns the corresponding [`Artist`](Artist) with all the information available,
+    /// through a new uncached [`Api`](Api).
+    pub fn get_full(&self) -> Result<Artist, DeezerError> {
+        Artist::get(self.id)
+    }
+
+    /// Returns the corresponding [`Artist`](Artist) through the given [`Api`](Api) and its cache.
+    pub fn get_full_with<T: Transport>(&self, api: &Api<T>) -> Result<Artist, DeezerError> {
+        api.get_artist(self.id)
+    }
+}
+
+/// Shortened version of [`Track`].
+/// Use [`.get_full()`] for the corresponding [`Track`] struct.
+///
+/// Returned by the user's favorite tracks, charts and flow.
+///
+/// [`Track`]: Track
+/// [`.get_full()`]: struct.UserTrack.html#method.get_full
+#[derive(Deserialize, Serialize, Debug)]
+pub struct UserTrack {
+
+    /// `The track's Deezer id`
+    pub id: u32,
+
+    /// `True if the track is readable in the player for the current user`
+    pub readable: bool,
+
+    /// `The track's full title`
+    pub title: String,
+
+    /// `The track's short title`
+    pub title_short: String,
+
+    /// `The track's version`
+    #[serde(default)]
+    pub title_version: String,
+
+    /// `The url of the track on Deezer`
+    pub link: String,
+
+    /// `The track's duration in seconds`
+    #[serde(rename = "duration")]
+    pub duration_in_seconds: u32,
+
+    /// `The track's Deezer rank`
+    pub rank: u32,
+
+    /// `Whether the track contains explicit lyrics`
+    #[serde(rename = "explicit_lyrics")]
+    pub has_explicit_lyrics: bool,
+
+    /// `The url of track's preview file. This file contains the first 30 seconds of the track`
+    #[serde(default)]
+    #[serde(rename = "preview")]
+    pub preview_url: Option<String>,
+
+    /// `The time when the track has been added to the user's favorites`
+    #[serde(default)]
+    #[serde(rename = "time_add")]
+    pub added_on: Option<u64>,
+
+    /// `Artist Object`
+    pub artist: UserTrackArtist,
+
+    /// `Album Object`
+    pub album: PlaylistTrackAlbum,
+}
+
+impl UserTrack {
+
+    /// Returns the corresponding [`Track`](Track) with all the information available,
+    /// through a new uncached [`Api`](Api).
+    pub fn get_full(&self) -> Result<Track, DeezerError> {
+        Track::get(self.id)
+    }
+
+    /// Returns the corresponding [`Track`](Track) through the given [`Api`](Api) and its cache.
+    pub fn get_full_with<T: Transport>(&self, api: &Api<T>) -> Result<Track, DeezerError> {
+        api.get_track(self.id)
+    }
+}
+
+/// Shortened version of [`Artist`].
+/// Use [`.get_full()`] for the corresponding [`Artist`] struct.
+///
+/// [`Artist`]: Artist
+/// [`.get_full()`]: struct.UserTrackArtist.html#method.get_full
+#[derive(Deserialize, Serialize, Debug)]
+pub struct UserTrackArtist {
+
+    /// `The artist's Deezer id`
+    pub id: u32,
+
+    /// `The artist's name`
+    pub name: String,
+}
 
-        // Get the track api
-        let user_api = get_user_api(id);
+impl UserTrackArtist {
 
-        // Get the json for the track
-        let mut resp = reqwest::get(&user_api).unwrap();
-        let json = resp.text().unwrap();
+    /// Returns the corresponding [`Artist`](Artist) with all the information available,
+    /// through a new uncached [`Api`](Api).
+    pub fn get_full(&self) -> Result<Artist, DeezerError> {
+        Artist::get(self.id)
+    }
 
-        Self::new(&json)
+    /// Returns the corresponding [`Artist`](Artist) through the given [`Api`](Api) and its cache.
+    pub fn get_full_with<T: Transport>(&self, api: &Api<T>) -> Result<Artist, DeezerError> {
+        api.get_artist(self.id)
     }
 }
 
-/// Takes an id and produces the appropriate api url.
-pub(crate) fn get_user_api(id: u32) -> String {
+/// Shortened version of [`Playlist`].
+/// Use [`.get_full()`] for the corresponding [`Playlist`] struct.
+///
+/// Returned by the user's playlists and charts.
+///
+/// [`Playlist`]: Playlist
+/// [`.get_full()`]: struct.UserPlaylist.html#method.get_full
+#[derive(Deserialize, Serialize, Debug)]
+pub struct UserPlaylist {
+
+    /// The playlist's Deezer id
+    pub id: u64,
+
+    /// The playlist's title
+    pub title: String,
+
+    /// The playlist's duration in seconds
+    #[serde(default)]
+    #[serde(rename = "duration")]
+    pub duration_in_seconds: Option<u32>,
+
+    /// If the playlist is public or not
+    #[serde(rename = "public")]
+    pub is_public: bool,
+
+    /// If the playlist is the love tracks playlist
+    #[serde(default)]
+    pub is_loved_track: bool,
+
+    /// If the playlist is collaborative or not
+    #[serde(default)]
+    #[serde(rename = "collaborative")]
+    pub is_collaborative: bool,
 
-    // Construct the api url with the given id
-    "https://api.deezer.com/user/".to_owned() + &id.to_string()
-}
\ No newline at end of file
+    /// Number of tracks in the playlist
+    pub nb_tracks: u32,
+
+    /// The number of playlist's fans
+    #[serde(default)]
+    pub fans: Option<u32>,
+
+    /// The url of the playlist on Deezer
+    pub link: String,
+
+    /// The url of the playlist's cover
+    pub picture: String,
+
+    /// The url of the playlist's cover in size small
+    pub picture_small: String,
+
+    /// The url of the playlist's cover in size medium
+    pub picture_medium: String,
+
+    /// The url of the playlist's cover in size big
+    pub picture_big: String,
+
+    /// The url of the playlist's cover in size xl
+    pub picture_xl: String,
+
+    /// The checksum for the track list
+    pub checksum: String,
+
+    /// The time when the playlist has been added to the user's playlists
+    #[serde(default)]
+    #[serde(rename = "time_add")]
+    pub added_on: Option<u64>,
+
+    /// The time when the playlist has last been modified
+    #[serde(default)]
+    #[serde(rename = "time_mod")]
+    pub modified_on: Option<u64>,
+
+    /// User object containing : id, name
+    pub creator: PlaylistUser,
+}
+
+impl UserPlaylist {
+
+    /// Returns the corresponding [`Playlist`](Playlist) with all the information available,
+    /// through a new uncached [`Api`](Api).
+    pub fn get_full(&self) -> Result<Playlist, DeezerError> {
+        Playlist::get(self.id)
+    }
+
+    /// Returns the corresponding [`Playlist`](Playlist) through the given [`Api`](Api) and its cache.
+    pub fn get_full_with<T: Transport>(&self, api: &Api<T>) -> Result<Playlist, DeezerError> {
+        api.get_playlist(self.id)
+    }
+}
+
+/// Takes an id and produces the appropriate api path.
+pub(crate) fn get_user_path(id: u32) -> String {
+
+    // Construct the api path with the given id
+    "user/".to_owned() + &id.to_string()
+}
+
+/// Takes an id and a relation (`albums`, `charts/tracks`, ...) and produces the appropriate api path.
+pub(crate) fn get_user_relation_path(id: u32, relation: &str) -> String {
+
+    // Construct the api path with the given id
+    format!("user/{}/{}", id, relation)
+}
+
+/// Produces the appropriate api path for the user the access token belongs to.
+pub(crate) fn get_me_path() -> String {
+
+    // Construct the api path
+    "user/me".to_owned()
+}
+
+/// Takes a relation (`albums`, `flow`, ...) and produces the appropriate api path
+/// for the user the access token belongs to.
+pub(crate) fn get_me_relation_path(relation: &str) -> String {
+
+    // Construct the api path
+    format!("user/me/{}", relation)
+}
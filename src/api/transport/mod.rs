@@ -0,0 +1,257 @@
+//! Contains the [`Transport`](Transport) trait [`Api`](Api) sends its requests through.
+//! Also contains the default [`ReqwestTransport`](ReqwestTransport) and an in-memory
+//! [`MemoryTransport`](MemoryTransport).
+#![deny(warnings, missing_docs)]
+
+use std::collections::HashMap;
+
+use reqwest::Client;
+use url::Url;
+
+use api::errors::DeezerError;
+
+/// The HTTP method of a [`Request`](Request).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Method {
+
+    /// `GET`
+    Get,
+
+    /// `POST`
+    Post,
+
+    /// `DELETE`
+    Delete,
+}
+
+/// A request [`Api`](Api) wants to send.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Request {
+
+    /// The HTTP method to use
+    pub method: Method,
+
+    /// The full url, query string included
+    pub url: String,
+}
+
+/// The answer to a [`Request`](Request).
+///
+/// Custom transports should build it with [`new`](#method.new), so fields added
+/// later don't break them.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Response {
+
+    /// The HTTP status code
+    pub status: u16,
+
+    /// The response body
+    pub body: String,
+
+    /// The url the body was served from after following redirects, if known
+    pub url: Option<String>,
+}
+
+impl Response {
+
+    /// Returns a `Response` with the given status code and body, served from an unknown url.
+    pub fn new(status: u16, body: &str) -> Self {
+
+        Response {
+            status,
+            body: body.to_owned(),
+            url: None,
+        }
+    }
+
+    /// Sets the url the body was served from after following redirects.
+    pub fn with_url(mut self, url: &str) -> Self {
+        self.url = Some(url.to_owned());
+        self
+    }
+}
+
+/// Sends requests on behalf of [`Api`](Api).
+///
+/// Implement it to route requests through a custom client, to record them as
+/// fixtures, or to answer them from memory in tests.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate deezer_metadata;
+/// # use deezer_metadata::api::Api;
+/// # use deezer_metadata::api::transport::MemoryTransport;
+/// # fn main() {
+/// let transport = MemoryTransport::new()
+///     .with_body("https://api.deezer.com/genre/132", r#"{
+///         "id": 132,
+///         "name": "Pop",
+///         "picture": "https://api.deezer.com/genre/132/image",
+///         "picture_small": "https://e-cdns-images.dzcdn.net/images/misc/small.jpg",
+///         "picture_medium": "https://e-cdns-images.dzcdn.net/images/misc/medium.jpg",
+///         "picture_big": "https://e-cdns-images.dzcdn.net/images/misc/big.jpg",
+///         "picture_xl": "https://e-cdns-images.dzcdn.net/images/misc/xl.jpg"
+///     }"#);
+///
+/// // Every request now goes through the given transport
+/// let deezer = Api::with_transport(transport);
+///
+/// let genre = deezer.get_genre(132).unwrap();
+/// assert_eq!(genre.name, "Pop");
+/// # }
+/// ```
+pub trait Transport {
+
+    /// Sends the request and returns Deezer's answer.
+    fn send(&self, request: &Request) -> Result<Response, DeezerError>;
+}
+
+impl<T: Transport + ?Sized> Transport for Box<T> {
+
+    fn send(&self, request: &Request) -> Result<Response, DeezerError> {
+        (**self).send(request)
+    }
+}
+
+impl<T: Transport + ?Sized> Transport for &T {
+
+    fn send(&self, request: &Request) -> Result<Response, DeezerError> {
+        (**self).send(request)
+    }
+}
+
+/// Query parameters whose values are masked before a url ends up in an error.
+const SECRET_PARAMS: &[&str] = &["access_token", "secret", "code"];
+
+/// Returns the url with the values of its secret query parameters (e.g. `access_token`) masked.
+pub(crate) fn redact_url(url: &str) -> String {
+
+    let mut parsed = match Url::parse(url) {
+        Ok(parsed) => parsed,
+        Err(_) => return url.split('?').next().unwrap_or("").to_owned(),
+    };
+
+    let pairs: Vec<(String, String)> = parsed.query_pairs()
+        .map(|(key, value)| {
+            let value = if SECRET_PARAMS.contains(&&*key) { "***".to_owned() } else { value.into_owned() };
+            (key.into_owned(), value)
+        })
+        .collect();
+
+    if !pairs.is_empty() {
+        parsed.query_pairs_mut().clear().extend_pairs(pairs);
+    }
+
+    parsed.into_string()
+}
+
+/// The default [`Transport`](Transport), backed by a `reqwest::Client`.
+///
+/// Its errors never show the secrets (`access_token`, `secret` or `code`)
+/// found in the url of the request.
+///
+/// # Examples
+///
+/// ```rust
+/// # extern crate deezer_metadata;
+/// # use deezer_metadata::api::transport::{Method, Request, ReqwestTransport, Transport};
+/// # fn main() {
+/// // Nothing listens on this port, so the request fails right away
+/// let request = Request {
+///     method: Method::Get,
+///     url: "http://127.0.0.1:1/user/me?access_token=t0k3n".to_owned(),
+/// };
+///
+/// let error = ReqwestTransport::new().send(&request).unwrap_err();
+/// assert!(!error.to_string().contains("t0k3n"));
+/// assert!(error.to_string().contains("access_token=***"));
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct ReqwestTransport {
+    client: Client,
+}
+
+impl ReqwestTransport {
+
+    /// Returns a `ReqwestTransport` with a default `reqwest::Client`.
+    pub fn new() -> Self {
+        Self::with_client(Client::new())
+    }
+
+    /// Returns a `ReqwestTransport` using the given client (e.g. one configured with a proxy).
+    pub fn with_client(client: Client) -> Self {
+        ReqwestTransport {
+            client,
+        }
+    }
+}
+
+impl Default for ReqwestTransport {
+
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Transport for ReqwestTransport {
+
+    fn send(&self, request: &Request) -> Result<Response, DeezerError> {
+
+        let method = match request.method {
+            Method::Get => reqwest::Method::GET,
+            Method::Post => reqwest::Method::POST,
+            Method::Delete => reqwest::Method::DELETE,
+        };
+
+        let mut resp = self.client.request(method, &request.url).send()?;
+        let url = resp.url().to_string();
+
+        Ok(Response::new(resp.status().as_u16(), &resp.text()?).with_url(&url))
+    }
+}
+
+/// A [`Transport`](Transport) answering from a fixed set of responses.
+///
+/// Requests nobody registered a response for fail with a
+/// [`DeezerError::Transport`](DeezerError::Transport).
+#[derive(Debug, Default, Clone)]
+pub struct MemoryTransport {
+    responses: HashMap<(Method, String), Response>,
+}
+
+impl MemoryTransport {
+
+    /// Returns a `MemoryTransport` without any responses.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Answers `GET` requests to `url` with a `200` and the given body.
+    pub fn with_body(self, url: &str, body: &str) -> Self {
+        self.with_response(Method::Get, url, Response::new(200, body))
+    }
+
+    /// Answers `GET` requests to `url` as if they were redirected to `location`.
+    pub fn with_redirect(self, url: &str, location: &str) -> Self {
+        self.with_response(Method::Get, url, Response::new(200, "").with_url(location))
+    }
+
+    /// Answers requests with the given method and url with the given response.
+    pub fn with_response(mut self, method: Method, url: &str, response: Response) -> Self {
+        self.responses.insert((method, url.to_owned()), response);
+        self
+    }
+}
+
+impl Transport for MemoryTransport {
+
+    fn send(&self, request: &Request) -> Result<Response, DeezerError> {
+        self.responses.get(&(request.method, request.url.clone()))
+            .cloned()
+            .ok_or_else(|| DeezerError::Transport(
+                format!("no response registered for {}", redact_url(&request.url)).into()
+            ))
+    }
+}
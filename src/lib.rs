@@ -1,9 +1,14 @@
 //! `deezloader_metadata` allows you to use deezloader's public API
 //! to get their available information on tracks, artists, albums, ...
 
+extern crate flate2;
+#[cfg(feature = "async")]
+extern crate futures;
 extern crate reqwest;
 extern crate serde;
 extern crate serde_json;
+extern crate serde_path_to_error;
+extern crate url;
 #[macro_use] extern crate serde_derive;
 
-pub mod api;
\ No newline at end of file
+pub mod api;